@@ -0,0 +1,164 @@
+use super::{Agent, View};
+use crate::choices::{BatchChoice, BatchChoices};
+use crate::game::{BatchChoiceGame, Config};
+use crate::outcomes::Outcome;
+
+#[derive(Debug, Clone)]
+pub struct Driver<A: Agent> {
+    game: BatchChoiceGame,
+    agents: Vec<A>,
+}
+
+impl<A: Agent> Driver<A> {
+    pub fn new(config: Config, agents: Vec<A>) -> Result<Self, ()> {
+        Self::from_game(BatchChoiceGame::new(config), agents)
+    }
+
+    pub fn from_game(game: BatchChoiceGame, agents: Vec<A>) -> Result<Self, ()> {
+        if game.player_count() != agents.len() || !game.config().has_stalemate_limit() {
+            Err(())
+        } else {
+            Ok(Self { game, agents })
+        }
+    }
+
+    pub fn game(&self) -> &BatchChoiceGame {
+        &self.game
+    }
+
+    pub fn agents(&self) -> &[A] {
+        &self.agents
+    }
+
+    pub fn into_agents(self) -> Vec<A> {
+        self.agents
+    }
+
+    pub fn step(&mut self) -> Result<Outcome, ()> {
+        let choice = self.decide()?;
+        self.apply(choice)
+    }
+
+    pub fn decide(&mut self) -> Result<BatchChoice, ()> {
+        let game = &self.game;
+        let view = |player_index| View { game, player_index };
+        let agents = self.agents.iter_mut().enumerate();
+
+        let choice = match game.choices() {
+            BatchChoices::Characters(choices) => BatchChoice::Characters(
+                agents
+                    .zip(choices)
+                    .map(|((i, agent), choices)| agent.choose_character(view(i), &choices))
+                    .collect(),
+            ),
+            BatchChoices::Boosters(choices) => BatchChoice::Boosters(
+                agents
+                    .zip(choices)
+                    .map(|((i, agent), choices)| agent.choose_booster(view(i), &choices))
+                    .collect(),
+            ),
+            BatchChoices::DequeueChoices(choices) => BatchChoice::DequeueChoices(
+                agents
+                    .zip(choices)
+                    .map(|((i, agent), choices)| agent.choose_dequeue_choice(view(i), &choices))
+                    .collect(),
+            ),
+            BatchChoices::Actions(choices) => BatchChoice::Actions(
+                agents
+                    .zip(choices)
+                    .map(|((i, agent), choices)| agent.choose_action(view(i), &choices))
+                    .collect(),
+            ),
+            BatchChoices::None => return Err(()),
+        };
+
+        Ok(choice)
+    }
+
+    pub fn apply(&mut self, choice: BatchChoice) -> Result<Outcome, ()> {
+        let outcome = self.game.choose(choice)?;
+        for agent in &mut self.agents {
+            agent.observe(&self.game, &outcome);
+        }
+        Ok(outcome)
+    }
+
+    pub fn run(&mut self) -> Result<Vec<Outcome>, ()> {
+        let mut outcomes = vec![];
+        while !self.game.is_over() {
+            outcomes.push(self.step()?);
+        }
+        Ok(outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{FirstLegalAgent, RandomAgent};
+
+    fn limited_config() -> Config {
+        Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn new_fails_if_agent_count_is_wrong() {
+        assert!(Driver::new(limited_config(), vec![FirstLegalAgent]).is_err());
+    }
+
+    #[test]
+    fn new_fails_without_stalemate_limit() {
+        assert!(Driver::new(Config::default(), vec![FirstLegalAgent, FirstLegalAgent]).is_err());
+    }
+
+    #[test]
+    fn run_plays_game_to_completion() {
+        let mut driver = Driver::new(
+            limited_config(),
+            vec![RandomAgent::new(0), RandomAgent::new(1)],
+        )
+        .unwrap();
+        let outcomes = driver.run().unwrap();
+
+        assert!(driver.game().is_over());
+        assert!(outcomes.last().unwrap().clone().game_over().is_some());
+    }
+
+    #[test]
+    fn run_is_reproducible() {
+        let play = || {
+            Driver::new(
+                limited_config(),
+                vec![RandomAgent::new(5), RandomAgent::new(6)],
+            )
+            .unwrap()
+            .run()
+            .unwrap()
+        };
+        assert_eq!(play(), play());
+    }
+
+    #[test]
+    fn mixed_agents_can_be_boxed() {
+        let agents: Vec<Box<dyn Agent>> =
+            vec![Box::new(FirstLegalAgent), Box::new(RandomAgent::new(9))];
+        let mut driver = Driver::new(limited_config(), agents).unwrap();
+        driver.run().unwrap();
+        assert!(driver.game().is_over());
+    }
+
+    #[test]
+    fn step_fails_if_game_is_over() {
+        let config = Config {
+            max_state_repetitions: Some(3),
+            ..Config::default()
+        };
+        let mut driver = Driver::new(config, vec![FirstLegalAgent, FirstLegalAgent]).unwrap();
+        driver.run().unwrap();
+        assert!(driver.step().is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use super::{Agent, View};
+use crate::choices::{Action, Booster, Character, DequeueChoice};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FirstLegalAgent;
+
+impl Agent for FirstLegalAgent {
+    fn choose_character(&mut self, _view: View, choices: &[Character]) -> Character {
+        choices[0]
+    }
+
+    fn choose_booster(&mut self, _view: View, choices: &[Booster]) -> Booster {
+        choices[0]
+    }
+
+    fn choose_dequeue_choice(&mut self, _view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        choices[0]
+    }
+
+    fn choose_action(&mut self, _view: View, choices: &[Action]) -> Action {
+        choices[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::BatchChoiceGame;
+
+    #[test]
+    fn chooses_first_choice() {
+        let game = BatchChoiceGame::default();
+        let view = View {
+            game: &game,
+            player_index: 0,
+        };
+        assert_eq!(
+            Character::Zombie,
+            FirstLegalAgent.choose_character(view, &[Character::Zombie, Character::Clown])
+        );
+    }
+}
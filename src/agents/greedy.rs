@@ -0,0 +1,294 @@
+use super::{Agent, View};
+use crate::analysis;
+use crate::choices::{
+    Action, ArsenalItem, Booster, Character, DequeueChoice, Move, MoveOutcomes, PointsAgainst,
+};
+
+const WIN_VALUE: f64 = 10.0;
+const DESTROYED_ITEM_COST: f64 = 0.5;
+const EXIT_DELAY_DISCOUNT: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GreedyAgent;
+
+impl GreedyAgent {
+    pub fn action_scores(view: View, choices: &[Action]) -> Vec<f64> {
+        let estimates =
+            analysis::estimate_actions(view.game, view.player_index).unwrap_or_default();
+
+        choices
+            .iter()
+            .map(|action| {
+                estimates
+                    .iter()
+                    .find(|e| e.action == *action)
+                    .map_or(0.0, |e| {
+                        e.expected_points + WIN_VALUE * (e.win_probability - e.loss_probability)
+                            - DESTROYED_ITEM_COST * e.destruction_risk
+                    })
+            })
+            .collect()
+    }
+
+    pub fn dequeue_choice_scores(view: View, choices: &[DequeueChoice]) -> Vec<f64> {
+        let game = view.game;
+        let me = view.player_index;
+        let queue = match game.queue(me) {
+            Some(queue) => queue,
+            None => return vec![0.0; choices.len()],
+        };
+        let opponent_moves: Vec<Move> = (0..game.player_count())
+            .filter(|&i| i != me)
+            .flat_map(|i| game.arsenal(i).unwrap_or_default().moves())
+            .collect();
+        let pool_moves: Vec<Move> = queue.pool().items().moves().iter().collect();
+        let outcomes = &game.config().move_outcomes;
+        let item_value = |item: ArsenalItem| match item {
+            ArsenalItem::Move(m) => move_value(outcomes, m, &opponent_moves),
+            ArsenalItem::Mirror => pool_moves
+                .iter()
+                .map(|&m| move_value(outcomes, m, &opponent_moves))
+                .fold(0.0, f64::max),
+        };
+        let exit_value = queue.exit().map_or(0.0, item_value);
+
+        choices
+            .iter()
+            .map(|choice| match choice {
+                DequeueChoice::Decline => 0.0,
+                DequeueChoice::JustExit => exit_value,
+                DequeueChoice::DrainAndExit(drainee) => {
+                    exit_value + EXIT_DELAY_DISCOUNT * item_value(*drainee)
+                }
+            })
+            .collect()
+    }
+}
+
+fn move_value(outcomes: &MoveOutcomes, m: Move, opponent_moves: &[Move]) -> f64 {
+    if opponent_moves.is_empty() {
+        return 1.0;
+    }
+    let advantage: i32 = opponent_moves
+        .iter()
+        .map(|&o| {
+            i32::from(outcomes.points_against(m, o)) - i32::from(outcomes.points_against(o, m))
+        })
+        .sum();
+    1.0 + f64::from(advantage) / opponent_moves.len() as f64
+}
+
+fn best<T: Copy>(choices: &[T], scores: &[f64]) -> T {
+    let mut best_index = 0;
+    for (i, score) in scores.iter().enumerate() {
+        if *score > scores[best_index] {
+            best_index = i;
+        }
+    }
+    choices[best_index]
+}
+
+impl Agent for GreedyAgent {
+    fn choose_character(&mut self, view: View, choices: &[Character]) -> Character {
+        let game = view.game;
+        let opponent_choices: Vec<Vec<Character>> = (0..game.player_count())
+            .filter(|&i| i != view.player_index)
+            .filter_map(|i| game.choices_for(i).and_then(|c| c.characters()))
+            .collect();
+        let scores: Vec<f64> = choices
+            .iter()
+            .map(|character| {
+                opponent_choices
+                    .iter()
+                    .map(|others| {
+                        let headstarts: u32 = others
+                            .iter()
+                            .map(|other| u32::from(character.points_against(other)))
+                            .sum();
+                        f64::from(headstarts) / others.len() as f64
+                    })
+                    .sum()
+            })
+            .collect();
+        best(choices, &scores)
+    }
+
+    fn choose_booster(&mut self, view: View, choices: &[Booster]) -> Booster {
+        let all_moves = Move::all();
+        let outcomes = &view.game.config().move_outcomes;
+        let scores: Vec<f64> = choices
+            .iter()
+            .map(|booster| {
+                booster
+                    .moves()
+                    .into_iter()
+                    .map(|m| move_value(outcomes, m, &all_moves))
+                    .sum()
+            })
+            .collect();
+        best(choices, &scores)
+    }
+
+    fn choose_dequeue_choice(&mut self, view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        best(choices, &Self::dequeue_choice_scores(view, choices))
+    }
+
+    fn choose_action(&mut self, view: View, choices: &[Action]) -> Action {
+        best(choices, &Self::action_scores(view, choices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{Driver, RandomAgent};
+    use crate::choices::BatchChoice;
+    use crate::game::{BatchChoiceGame, Config};
+
+    fn ninja_samurai_action_phase() -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+        ]))
+        .unwrap();
+        game
+    }
+
+    fn ninja_samurai_dequeue_phase_with_pool_moves() -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        for &(ninja, samurai) in &[
+            (Move::NinjaSword, Move::SamuraiSword),
+            (Move::Nunchucks, Move::Helmet),
+        ] {
+            game.choose(BatchChoice::DequeueChoices(vec![
+                DequeueChoice::Decline,
+                DequeueChoice::Decline,
+            ]))
+            .unwrap();
+            game.choose(BatchChoice::Actions(vec![
+                Action::Move(ninja),
+                Action::Move(samurai),
+            ]))
+            .unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn action_scores_rank_stronger_moves_higher() {
+        let game = ninja_samurai_action_phase();
+        let view = View {
+            game: &game,
+            player_index: 0,
+        };
+        let choices = vec![
+            Action::Move(Move::ShadowSlip),
+            Action::Move(Move::NinjaSword),
+            Action::Move(Move::ShadowFireball),
+        ];
+        let scores = GreedyAgent::action_scores(view, &choices);
+
+        assert!(scores[2] > scores[1]);
+        assert!(scores[1] > scores[0]);
+        assert_eq!(
+            Action::Move(Move::ShadowFireball),
+            GreedyAgent.choose_action(view, &choices)
+        );
+    }
+
+    #[test]
+    fn dequeue_prefers_mirror_if_pool_has_strong_moves() {
+        let game = ninja_samurai_dequeue_phase_with_pool_moves();
+        let pool = game.queue(0).unwrap().pool();
+        assert!(pool.contains(ArsenalItem::Move(Move::NinjaSword)));
+        assert!(pool.contains(ArsenalItem::Mirror));
+
+        let view = View {
+            game: &game,
+            player_index: 0,
+        };
+        let choices = [
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::Decline,
+        ];
+        let scores = GreedyAgent::dequeue_choice_scores(view, &choices);
+        assert!(scores[0] > scores[1]);
+    }
+
+    #[test]
+    fn booster_pick_follows_config_move_outcomes() {
+        let pick = |config: Config| {
+            let mut game = BatchChoiceGame::new(config);
+            game.choose(BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Samurai,
+            ]))
+            .unwrap();
+            let choices = game.choices_for(0).unwrap().boosters().unwrap();
+            let view = View {
+                game: &game,
+                player_index: 0,
+            };
+            (GreedyAgent.choose_booster(view, &choices), choices)
+        };
+        let (standard_pick, choices) = pick(Config::default());
+        let favored = *choices
+            .iter()
+            .find(|&&b| b != standard_pick && !b.moves().is_empty())
+            .unwrap();
+
+        let mut move_outcomes = MoveOutcomes::default();
+        for m in favored.moves() {
+            for other in Move::all() {
+                if other != m {
+                    move_outcomes.set(m, other, 1);
+                    move_outcomes.set(other, m, 0);
+                }
+            }
+        }
+        let (pick, _) = pick(Config {
+            move_outcomes,
+            ..Config::default()
+        });
+
+        assert_eq!(favored, pick);
+    }
+
+    #[test]
+    fn greedy_beats_random_more_often_than_not() {
+        let config = Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        };
+        let mut greedy_wins = 0;
+        let mut random_wins = 0;
+        for seed in 0..40 {
+            let agents: Vec<Box<dyn Agent>> =
+                vec![Box::new(GreedyAgent), Box::new(RandomAgent::new(seed))];
+            let mut driver = Driver::new(config.clone(), agents).unwrap();
+            driver.run().unwrap();
+            match driver.game().winner_index() {
+                Some(0) => greedy_wins += 1,
+                Some(_) => random_wins += 1,
+                None => {}
+            }
+        }
+        assert!(greedy_wins > random_wins);
+    }
+}
@@ -0,0 +1,322 @@
+use super::{Agent, View};
+use crate::choices::{Action, BatchChoice, Booster, Character, Choice, DequeueChoice};
+use crate::game::BatchChoiceGame;
+use crate::random::Prng;
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MctsConfig {
+    pub iterations: u32,
+    pub time_limit: Option<Duration>,
+    pub exploration: f64,
+    pub max_rollout_choices: u32,
+    pub seed: u64,
+}
+
+impl Default for MctsConfig {
+    fn default() -> MctsConfig {
+        MctsConfig {
+            iterations: 1000,
+            time_limit: None,
+            exploration: std::f64::consts::SQRT_2,
+            max_rollout_choices: 200,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MctsAgent {
+    config: MctsConfig,
+    rng: Prng,
+}
+
+impl MctsAgent {
+    pub fn new(config: MctsConfig) -> Self {
+        let rng = Prng::new(config.seed);
+        Self { config, rng }
+    }
+
+    pub fn search(&mut self, game: &BatchChoiceGame, player_index: usize) -> Vec<(Choice, u32)> {
+        let mut root = Node::new(game);
+        let started = Instant::now();
+
+        for _ in 0..self.config.iterations {
+            if let Some(limit) = self.config.time_limit {
+                if started.elapsed() >= limit {
+                    break;
+                }
+            }
+            let mut game = game.clone();
+            root.iterate(&mut game, &self.config, &mut self.rng);
+        }
+
+        match root.arms.get(player_index) {
+            Some(arms) => arms.iter().map(|arm| (arm.choice, arm.visits)).collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn best_choice(&mut self, game: &BatchChoiceGame, player_index: usize) -> Option<Choice> {
+        let mut best: Option<(Choice, u32)> = None;
+        for (choice, visits) in self.search(game, player_index) {
+            let better = match best {
+                Some((_, best_visits)) => visits > best_visits,
+                None => true,
+            };
+            if better {
+                best = Some((choice, visits));
+            }
+        }
+        best.map(|(choice, _)| choice)
+    }
+
+    fn choose<T: Copy>(
+        &mut self,
+        view: View,
+        choices: &[T],
+        extract: impl Fn(Choice) -> Option<T>,
+    ) -> T {
+        self.best_choice(view.game, view.player_index)
+            .and_then(extract)
+            .unwrap_or(choices[0])
+    }
+}
+
+impl Agent for MctsAgent {
+    fn choose_character(&mut self, view: View, choices: &[Character]) -> Character {
+        self.choose(view, choices, Choice::character)
+    }
+
+    fn choose_booster(&mut self, view: View, choices: &[Booster]) -> Booster {
+        self.choose(view, choices, Choice::booster)
+    }
+
+    fn choose_dequeue_choice(&mut self, view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        self.choose(view, choices, Choice::dequeue_choice)
+    }
+
+    fn choose_action(&mut self, view: View, choices: &[Action]) -> Action {
+        self.choose(view, choices, Choice::action)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Arm {
+    choice: Choice,
+    visits: u32,
+    reward: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    arms: Vec<Vec<Arm>>,
+    visits: u32,
+    children: HashMap<Vec<usize>, Node>,
+}
+
+impl Node {
+    fn new(game: &BatchChoiceGame) -> Self {
+        let arms = game
+            .choices()
+            .to_choices()
+            .into_iter()
+            .map(|choices| {
+                choices
+                    .into_iter()
+                    .map(|choice| Arm {
+                        choice,
+                        visits: 0,
+                        reward: 0.0,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            arms,
+            visits: 0,
+            children: HashMap::new(),
+        }
+    }
+
+    fn iterate(
+        &mut self,
+        game: &mut BatchChoiceGame,
+        config: &MctsConfig,
+        rng: &mut Prng,
+    ) -> Vec<f64> {
+        if self.arms.is_empty() {
+            return rewards(game);
+        }
+
+        let selected: Vec<usize> = self
+            .arms
+            .iter()
+            .map(|arms| select(arms, self.visits, config.exploration))
+            .collect();
+        let choice = BatchChoice::from_choices(
+            selected
+                .iter()
+                .zip(&self.arms)
+                .map(|(&i, arms)| arms[i].choice)
+                .collect(),
+        )
+        .expect("arms of one node share a phase");
+        game.choose(choice).expect("arms are legal choices");
+
+        let rewards = match self.children.get_mut(&selected) {
+            Some(child) => child.iterate(game, config, rng),
+            None => {
+                self.children.insert(selected.clone(), Node::new(game));
+                rollout(game, config.max_rollout_choices, rng)
+            }
+        };
+
+        self.visits += 1;
+        for ((arms, &i), reward) in self.arms.iter_mut().zip(&selected).zip(&rewards) {
+            arms[i].visits += 1;
+            arms[i].reward += reward;
+        }
+        rewards
+    }
+}
+
+fn select(arms: &[Arm], parent_visits: u32, exploration: f64) -> usize {
+    if let Some(unvisited) = arms.iter().position(|arm| arm.visits == 0) {
+        return unvisited;
+    }
+
+    let log_visits = f64::from(parent_visits.max(1)).ln();
+    let mut best_index = 0;
+    let mut best_score = f64::NEG_INFINITY;
+    for (i, arm) in arms.iter().enumerate() {
+        let visits = f64::from(arm.visits);
+        let score = arm.reward / visits + exploration * (log_visits / visits).sqrt();
+        if score > best_score {
+            best_index = i;
+            best_score = score;
+        }
+    }
+    best_index
+}
+
+fn rollout(game: &mut BatchChoiceGame, max_choices: u32, rng: &mut Prng) -> Vec<f64> {
+    for _ in 0..max_choices {
+        match game.choices().random(rng) {
+            Some(choice) => {
+                game.choose(choice)
+                    .expect("random choices are always legal");
+            }
+            None => break,
+        }
+    }
+    rewards(game)
+}
+
+pub(crate) fn rewards(game: &BatchChoiceGame) -> Vec<f64> {
+    let player_count = game.player_count();
+    if let Some(winner) = game.winner_index() {
+        return (0..player_count)
+            .map(|i| if i == winner { 1.0 } else { 0.0 })
+            .collect();
+    }
+
+    let points: Vec<f64> = (0..player_count)
+        .map(|i| f64::from(game.points(i).unwrap_or(0)) + 1.0)
+        .collect();
+    let total: f64 = points.iter().sum();
+    points.iter().map(|p| p / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{Driver, RandomAgent};
+    use crate::game::Config;
+
+    fn quick_config(seed: u64) -> MctsConfig {
+        MctsConfig {
+            iterations: 200,
+            seed,
+            ..MctsConfig::default()
+        }
+    }
+
+    #[test]
+    fn search_visits_every_legal_choice() {
+        let game = BatchChoiceGame::default();
+        let results = MctsAgent::new(quick_config(0)).search(&game, 0);
+
+        assert_eq!(Character::all().len(), results.len());
+        assert!(results.iter().all(|(_, visits)| *visits > 0));
+    }
+
+    #[test]
+    fn search_respects_time_limit() {
+        let game = BatchChoiceGame::default();
+        let mut agent = MctsAgent::new(MctsConfig {
+            iterations: u32::MAX,
+            time_limit: Some(Duration::from_millis(20)),
+            ..MctsConfig::default()
+        });
+        assert!(agent.best_choice(&game, 0).is_some());
+    }
+
+    #[test]
+    fn search_is_reproducible() {
+        let game = BatchChoiceGame::default();
+        assert_eq!(
+            MctsAgent::new(quick_config(4)).search(&game, 1),
+            MctsAgent::new(quick_config(4)).search(&game, 1)
+        );
+    }
+
+    #[test]
+    fn best_choice_is_none_if_game_is_over() {
+        let mut game = BatchChoiceGame::new(Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        });
+        let mut rng = Prng::new(0);
+        while let Some(choice) = game.choices().random(&mut rng) {
+            game.choose(choice).unwrap();
+        }
+        assert_eq!(None, MctsAgent::new(quick_config(0)).best_choice(&game, 0));
+    }
+
+    #[test]
+    fn mcts_beats_random_more_often_than_not() {
+        let config = Config {
+            points_to_win: 3,
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        };
+        let mut mcts_wins = 0;
+        let mut random_wins = 0;
+        for seed in 0..6 {
+            let agents: Vec<Box<dyn Agent>> = vec![
+                Box::new(MctsAgent::new(quick_config(seed))),
+                Box::new(RandomAgent::new(seed)),
+            ];
+            let mut driver = Driver::new(config.clone(), agents).unwrap();
+            driver.run().unwrap();
+            match driver.game().winner_index() {
+                Some(0) => mcts_wins += 1,
+                Some(_) => random_wins += 1,
+                None => {}
+            }
+        }
+        assert!(mcts_wins > random_wins);
+    }
+
+    #[test]
+    fn rewards_are_even_before_anyone_scores() {
+        let game = BatchChoiceGame::default();
+        assert_eq!(vec![0.5, 0.5], rewards(&game));
+    }
+}
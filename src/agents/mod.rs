@@ -0,0 +1,55 @@
+use crate::choices::{Action, Booster, Character, DequeueChoice};
+use crate::game::BatchChoiceGame;
+use crate::outcomes::Outcome;
+
+mod driver;
+pub use driver::Driver;
+mod first_legal;
+pub use first_legal::FirstLegalAgent;
+mod greedy;
+pub use greedy::GreedyAgent;
+mod mcts;
+pub(crate) use mcts::rewards;
+pub use mcts::{MctsAgent, MctsConfig};
+mod modeling;
+pub use modeling::{ModelingAgent, OpponentModel};
+mod profile;
+pub use profile::ProfileAgent;
+mod random;
+pub use random::RandomAgent;
+
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a> {
+    pub game: &'a BatchChoiceGame,
+    pub player_index: usize,
+}
+
+pub trait Agent {
+    fn choose_character(&mut self, view: View, choices: &[Character]) -> Character;
+    fn choose_booster(&mut self, view: View, choices: &[Booster]) -> Booster;
+    fn choose_dequeue_choice(&mut self, view: View, choices: &[DequeueChoice]) -> DequeueChoice;
+    fn choose_action(&mut self, view: View, choices: &[Action]) -> Action;
+    fn observe(&mut self, _game: &BatchChoiceGame, _outcome: &Outcome) {}
+}
+
+impl<A: Agent + ?Sized> Agent for Box<A> {
+    fn choose_character(&mut self, view: View, choices: &[Character]) -> Character {
+        (**self).choose_character(view, choices)
+    }
+
+    fn choose_booster(&mut self, view: View, choices: &[Booster]) -> Booster {
+        (**self).choose_booster(view, choices)
+    }
+
+    fn choose_dequeue_choice(&mut self, view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        (**self).choose_dequeue_choice(view, choices)
+    }
+
+    fn choose_action(&mut self, view: View, choices: &[Action]) -> Action {
+        (**self).choose_action(view, choices)
+    }
+
+    fn observe(&mut self, game: &BatchChoiceGame, outcome: &Outcome) {
+        (**self).observe(game, outcome)
+    }
+}
@@ -0,0 +1,361 @@
+use super::{Agent, GreedyAgent, View};
+use crate::choices::{Action, Booster, Character, DequeueChoice, PointsAgainst};
+use crate::game::BatchChoiceGame;
+use crate::outcomes::Outcome;
+use crate::random::Prng;
+
+const PSEUDOCOUNT: f64 = 0.5;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct History {
+    characters: Vec<Character>,
+    boosters: Vec<Booster>,
+    dequeue_choices: Vec<DequeueChoice>,
+    actions: Vec<Action>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OpponentModel {
+    order: usize,
+    histories: Vec<History>,
+}
+
+impl OpponentModel {
+    pub fn new(order: usize) -> Self {
+        Self {
+            order,
+            histories: vec![],
+        }
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    pub fn observe(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::CharacterPhaseRechoose(characters) => {
+                for (history, character) in self.histories(characters.len()).zip(characters) {
+                    history.characters.push(*character);
+                }
+            }
+            Outcome::CharacterPhaseDone(headstarts) => {
+                for (history, headstart) in self.histories(headstarts.len()).zip(headstarts) {
+                    history.characters.push(headstart.0);
+                }
+            }
+            Outcome::BoosterPhaseDone(boosters) => {
+                for (history, booster) in self.histories(boosters.len()).zip(boosters) {
+                    history.boosters.push(*booster);
+                }
+            }
+            Outcome::DequeuePhaseDone(dequeue_choices) => {
+                for (history, dequeue_choice) in
+                    self.histories(dequeue_choices.len()).zip(dequeue_choices)
+                {
+                    history.dequeue_choices.push(*dequeue_choice);
+                }
+            }
+            Outcome::ActionPhaseDone(apds) | Outcome::GameOver(apds) | Outcome::Draw(apds) => {
+                for (history, apd) in self.histories(apds.len()).zip(apds) {
+                    history.actions.push(apd.0);
+                }
+            }
+        }
+    }
+
+    pub fn predict_character(&self, player_index: usize, choices: &[Character]) -> Vec<f64> {
+        predict(
+            self.history(player_index).map(|h| &h.characters[..]),
+            self.order,
+            choices,
+        )
+    }
+
+    pub fn predict_booster(&self, player_index: usize, choices: &[Booster]) -> Vec<f64> {
+        predict(
+            self.history(player_index).map(|h| &h.boosters[..]),
+            self.order,
+            choices,
+        )
+    }
+
+    pub fn predict_dequeue_choice(
+        &self,
+        player_index: usize,
+        choices: &[DequeueChoice],
+    ) -> Vec<f64> {
+        predict(
+            self.history(player_index).map(|h| &h.dequeue_choices[..]),
+            self.order,
+            choices,
+        )
+    }
+
+    pub fn predict_action(&self, player_index: usize, choices: &[Action]) -> Vec<f64> {
+        predict(
+            self.history(player_index).map(|h| &h.actions[..]),
+            self.order,
+            choices,
+        )
+    }
+
+    fn history(&self, player_index: usize) -> Option<&History> {
+        self.histories.get(player_index)
+    }
+
+    fn histories(&mut self, player_count: usize) -> impl Iterator<Item = &mut History> {
+        if self.histories.len() < player_count {
+            self.histories.resize_with(player_count, History::default);
+        }
+        self.histories.iter_mut()
+    }
+}
+
+impl Default for OpponentModel {
+    fn default() -> OpponentModel {
+        OpponentModel::new(2)
+    }
+}
+
+fn predict<T: PartialEq>(history: Option<&[T]>, order: usize, choices: &[T]) -> Vec<f64> {
+    let uniform = vec![1.0 / choices.len() as f64; choices.len()];
+    let history = match history {
+        Some(history) => history,
+        None => return uniform,
+    };
+
+    for context_len in (0..=order.min(history.len())).rev() {
+        let context = &history[history.len() - context_len..];
+        let mut counts = vec![0.0; choices.len()];
+        for i in context_len..history.len() {
+            if &history[i - context_len..i] == context {
+                if let Some(j) = choices.iter().position(|c| c == &history[i]) {
+                    counts[j] += 1.0;
+                }
+            }
+        }
+
+        let total: f64 = counts.iter().sum();
+        if total > 0.0 {
+            let denominator = total + PSEUDOCOUNT * choices.len() as f64;
+            return counts
+                .iter()
+                .map(|count| (count + PSEUDOCOUNT) / denominator)
+                .collect();
+        }
+    }
+
+    uniform
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModelingAgent {
+    model: OpponentModel,
+    rng: Prng,
+}
+
+impl ModelingAgent {
+    pub fn new(model: OpponentModel, seed: u64) -> Self {
+        Self {
+            model,
+            rng: Prng::new(seed),
+        }
+    }
+
+    pub fn model(&self) -> &OpponentModel {
+        &self.model
+    }
+
+    pub fn action_scores(&self, view: View, choices: &[Action]) -> Vec<f64> {
+        let outcomes = &view.game.config().move_outcomes;
+        self.scores(
+            view,
+            choices,
+            |game, i| {
+                let opponent_choices = game.choices_for(i)?.actions()?;
+                let prediction = self.model.predict_action(i, &opponent_choices);
+                Some(opponent_choices.into_iter().zip(prediction).collect())
+            },
+            |a, b| outcomes.action_points_against(*a, *b),
+        )
+    }
+
+    pub fn character_scores(&self, view: View, choices: &[Character]) -> Vec<f64> {
+        self.scores(
+            view,
+            choices,
+            |game, i| {
+                let opponent_choices = game.choices_for(i)?.characters()?;
+                let prediction = self.model.predict_character(i, &opponent_choices);
+                Some(opponent_choices.into_iter().zip(prediction).collect())
+            },
+            |a, b| a.points_against(b),
+        )
+    }
+
+    fn scores<T, F, P>(&self, view: View, choices: &[T], predict: F, points_against: P) -> Vec<f64>
+    where
+        F: Fn(&BatchChoiceGame, usize) -> Option<Vec<(T, f64)>>,
+        P: Fn(&T, &T) -> u8,
+    {
+        let predictions: Vec<Vec<(T, f64)>> = (0..view.game.player_count())
+            .filter(|&i| i != view.player_index)
+            .filter_map(|i| predict(view.game, i))
+            .collect();
+
+        choices
+            .iter()
+            .map(|choice| {
+                predictions
+                    .iter()
+                    .flatten()
+                    .map(|(other, p)| {
+                        p * (f64::from(points_against(choice, other))
+                            - f64::from(points_against(other, choice)))
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn best<T: Copy>(&mut self, choices: &[T], scores: &[f64]) -> T {
+        let best_score = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let best: Vec<T> = choices
+            .iter()
+            .zip(scores)
+            .filter(|(_, &score)| score >= best_score - 1e-9)
+            .map(|(choice, _)| *choice)
+            .collect();
+        best[self.rng.below(best.len())]
+    }
+}
+
+impl Default for ModelingAgent {
+    fn default() -> ModelingAgent {
+        ModelingAgent::new(OpponentModel::default(), 0)
+    }
+}
+
+impl Agent for ModelingAgent {
+    fn choose_character(&mut self, view: View, choices: &[Character]) -> Character {
+        let scores = self.character_scores(view, choices);
+        self.best(choices, &scores)
+    }
+
+    fn choose_booster(&mut self, view: View, choices: &[Booster]) -> Booster {
+        GreedyAgent.choose_booster(view, choices)
+    }
+
+    fn choose_dequeue_choice(&mut self, view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        GreedyAgent.choose_dequeue_choice(view, choices)
+    }
+
+    fn choose_action(&mut self, view: View, choices: &[Action]) -> Action {
+        let scores = self.action_scores(view, choices);
+        self.best(choices, &scores)
+    }
+
+    fn observe(&mut self, _game: &BatchChoiceGame, outcome: &Outcome) {
+        self.model.observe(outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{Driver, FirstLegalAgent, RandomAgent};
+    use crate::choices::Move;
+    use crate::game::Config;
+    use crate::outcomes::{ActionPointsDestroyed, CharacterHeadstart};
+
+    fn limited_config() -> Config {
+        Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        }
+    }
+
+    fn apd(m: Move) -> ActionPointsDestroyed {
+        ActionPointsDestroyed(Action::Move(m), 0, false)
+    }
+
+    #[test]
+    fn unobserved_player_is_predicted_uniformly() {
+        let model = OpponentModel::default();
+        assert_eq!(vec![0.25; 4], model.predict_character(1, &Character::all()));
+    }
+
+    #[test]
+    fn frequent_choice_is_predicted_most_likely() {
+        let mut model = OpponentModel::new(0);
+        for _ in 0..3 {
+            model.observe(&Outcome::CharacterPhaseDone(vec![
+                CharacterHeadstart(Character::Ninja, 0),
+                CharacterHeadstart(Character::Clown, 0),
+            ]));
+        }
+        let characters = Character::all();
+        let prediction = model.predict_character(1, &characters);
+        let clown = characters
+            .iter()
+            .position(|&c| c == Character::Clown)
+            .unwrap();
+
+        assert!((prediction.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        for (i, p) in prediction.iter().enumerate() {
+            if i != clown {
+                assert!(prediction[clown] > *p);
+            }
+        }
+    }
+
+    #[test]
+    fn ngram_model_predicts_alternation() {
+        let mut model = OpponentModel::new(1);
+        for m in &[Move::Kick, Move::NinjaSword, Move::Kick, Move::NinjaSword] {
+            model.observe(&Outcome::ActionPhaseDone(vec![apd(*m), apd(Move::Kick)]));
+        }
+        let choices = vec![Action::Move(Move::Kick), Action::Move(Move::NinjaSword)];
+        let prediction = model.predict_action(0, &choices);
+        assert!(prediction[0] > prediction[1]);
+    }
+
+    #[test]
+    fn prediction_ignores_illegal_choices() {
+        let mut model = OpponentModel::new(0);
+        model.observe(&Outcome::ActionPhaseDone(vec![apd(Move::Kick)]));
+        let prediction = model.predict_action(0, &[Action::Move(Move::NinjaSword)]);
+        assert_eq!(vec![1.0], prediction);
+    }
+
+    #[test]
+    fn modeling_agent_beats_predictable_agent() {
+        let mut agents: Vec<Box<dyn Agent>> = vec![
+            Box::new(ModelingAgent::default()),
+            Box::new(FirstLegalAgent),
+        ];
+        let mut wins = 0;
+        for _ in 0..20 {
+            let mut driver = Driver::new(limited_config(), agents).unwrap();
+            driver.run().unwrap();
+            if driver.game().winner_index() == Some(0) {
+                wins += 1;
+            }
+            agents = driver.into_agents();
+        }
+        assert!(wins >= 15);
+    }
+
+    #[test]
+    fn modeling_agent_finishes_games_against_random() {
+        let agents: Vec<Box<dyn Agent>> = vec![
+            Box::new(ModelingAgent::default()),
+            Box::new(RandomAgent::new(1)),
+        ];
+        let mut driver = Driver::new(limited_config(), agents).unwrap();
+        driver.run().unwrap();
+        assert!(driver.game().is_over());
+    }
+}
@@ -0,0 +1,118 @@
+use super::{Agent, View};
+use crate::choices::{Action, Booster, Character, Choice, DequeueChoice};
+use crate::random::Prng;
+use crate::solver::{sample, StrategyProfile};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileAgent {
+    profile: StrategyProfile,
+    rng: Prng,
+}
+
+impl ProfileAgent {
+    pub fn new(profile: StrategyProfile, seed: u64) -> Self {
+        Self {
+            profile,
+            rng: Prng::new(seed),
+        }
+    }
+
+    pub fn profile(&self) -> &StrategyProfile {
+        &self.profile
+    }
+
+    fn pick<T: Copy + PartialEq>(
+        &mut self,
+        view: View,
+        choices: &[T],
+        extract: fn(Choice) -> Option<T>,
+    ) -> T {
+        if let Some(strategy) = self.profile.strategy(view.game, view.player_index) {
+            let probabilities: Vec<f64> = strategy.iter().map(|(_, p)| *p).collect();
+            let (choice, _) = strategy[sample(&probabilities, &mut self.rng)];
+            if let Some(choice) = extract(choice).filter(|c| choices.contains(c)) {
+                return choice;
+            }
+        }
+
+        choices[self.rng.below(choices.len())]
+    }
+}
+
+impl Agent for ProfileAgent {
+    fn choose_character(&mut self, view: View, choices: &[Character]) -> Character {
+        self.pick(view, choices, Choice::character)
+    }
+
+    fn choose_booster(&mut self, view: View, choices: &[Booster]) -> Booster {
+        self.pick(view, choices, Choice::booster)
+    }
+
+    fn choose_dequeue_choice(&mut self, view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        self.pick(view, choices, Choice::dequeue_choice)
+    }
+
+    fn choose_action(&mut self, view: View, choices: &[Action]) -> Action {
+        self.pick(view, choices, Choice::action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{Driver, RandomAgent};
+    use crate::game::{BatchChoiceGame, Config};
+    use crate::solver::{information_state_key, CfrConfig, CfrTrainer};
+
+    #[test]
+    fn plays_pure_strategy_from_profile() {
+        let game = BatchChoiceGame::default();
+        let mut profile = StrategyProfile::new();
+        profile.insert(information_state_key(&game, 0), vec![0.0, 0.0, 1.0, 0.0]);
+        let mut agent = ProfileAgent::new(profile, 0);
+        let view = View {
+            game: &game,
+            player_index: 0,
+        };
+        let characters = game.choices_for(0).unwrap().characters().unwrap();
+        for _ in 0..10 {
+            assert_eq!(characters[2], agent.choose_character(view, &characters));
+        }
+    }
+
+    #[test]
+    fn falls_back_to_legal_choice_without_strategy() {
+        let game = BatchChoiceGame::default();
+        let mut agent = ProfileAgent::new(StrategyProfile::new(), 0);
+        let view = View {
+            game: &game,
+            player_index: 1,
+        };
+        let characters = Character::all();
+        assert!(characters.contains(&agent.choose_character(view, &characters)));
+    }
+
+    #[test]
+    fn trained_agent_finishes_games() {
+        let config = Config {
+            points_to_win: 2,
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        };
+        let profile = CfrTrainer::new(CfrConfig {
+            iterations: 10,
+            max_depth: Some(2),
+            ..CfrConfig::default()
+        })
+        .train(&BatchChoiceGame::new(config.clone()))
+        .unwrap();
+        let agents: Vec<Box<dyn Agent>> = vec![
+            Box::new(ProfileAgent::new(profile, 1)),
+            Box::new(RandomAgent::new(2)),
+        ];
+        let mut driver = Driver::new(config, agents).unwrap();
+        driver.run().unwrap();
+        assert!(driver.game().is_over());
+    }
+}
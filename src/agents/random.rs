@@ -0,0 +1,76 @@
+use super::{Agent, View};
+use crate::choices::{Action, Booster, Character, DequeueChoice};
+use crate::random::Prng;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RandomAgent {
+    rng: Prng,
+}
+
+impl RandomAgent {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Prng::new(seed),
+        }
+    }
+
+    fn pick<T: Copy>(&mut self, choices: &[T]) -> T {
+        choices[self.rng.below(choices.len())]
+    }
+}
+
+impl Agent for RandomAgent {
+    fn choose_character(&mut self, _view: View, choices: &[Character]) -> Character {
+        self.pick(choices)
+    }
+
+    fn choose_booster(&mut self, _view: View, choices: &[Booster]) -> Booster {
+        self.pick(choices)
+    }
+
+    fn choose_dequeue_choice(&mut self, _view: View, choices: &[DequeueChoice]) -> DequeueChoice {
+        self.pick(choices)
+    }
+
+    fn choose_action(&mut self, _view: View, choices: &[Action]) -> Action {
+        self.pick(choices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::BatchChoiceGame;
+
+    #[test]
+    fn same_seed_makes_same_choices() {
+        let game = BatchChoiceGame::default();
+        let view = View {
+            game: &game,
+            player_index: 0,
+        };
+        let characters = Character::all();
+        let mut a = RandomAgent::new(3);
+        let mut b = RandomAgent::new(3);
+        for _ in 0..20 {
+            assert_eq!(
+                a.choose_character(view, &characters),
+                b.choose_character(view, &characters)
+            );
+        }
+    }
+
+    #[test]
+    fn only_makes_legal_choices() {
+        let game = BatchChoiceGame::default();
+        let view = View {
+            game: &game,
+            player_index: 0,
+        };
+        let boosters = Character::Ninja.boosters();
+        let mut agent = RandomAgent::new(0);
+        for _ in 0..20 {
+            assert!(boosters.contains(&agent.choose_booster(view, &boosters)));
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use crate::choices::Action;
+use crate::game::BatchChoiceGame;
+use crate::helpers;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionEstimate {
+    pub action: Action,
+    pub expected_points: f64,
+    pub destruction_risk: f64,
+    pub win_probability: f64,
+    pub loss_probability: f64,
+}
+
+pub(crate) struct RoundResult {
+    pub(crate) points_gained: Vec<i16>,
+    pub(crate) destroyed: Vec<bool>,
+    pub(crate) winner: Option<usize>,
+}
+
+pub(crate) fn resolve_round(game: &BatchChoiceGame, actions: &[Action]) -> RoundResult {
+    let current: Vec<u8> = (0..game.player_count())
+        .map(|i| game.points(i).unwrap_or(0))
+        .collect();
+    let config = game.config();
+
+    let gained = config.move_outcomes.points_of(actions);
+    let new_points: Vec<u8> = current.iter().zip(&gained).map(|(c, g)| c + g).collect();
+    let deductions = config.deductions(new_points.clone());
+    let final_points: Vec<i16> = new_points
+        .iter()
+        .zip(deductions)
+        .map(|(p, d)| (i16::from(*p) - i16::from(d)).max(0))
+        .collect();
+
+    RoundResult {
+        points_gained: final_points
+            .iter()
+            .zip(&current)
+            .map(|(f, c)| f - i16::from(*c))
+            .collect(),
+        destroyed: Action::which_destroyed(actions)
+            .into_iter()
+            .zip(actions)
+            .map(|(destroyed, action)| destroyed && *action != Action::Concede)
+            .collect(),
+        winner: final_points
+            .iter()
+            .position(|&p| p == i16::from(config.points_to_win)),
+    }
+}
+
+pub fn estimate_actions(
+    game: &BatchChoiceGame,
+    player_index: usize,
+) -> Option<Vec<ActionEstimate>> {
+    let strategies = uniform_strategies(game)?;
+    estimate_actions_against(game, player_index, &strategies)
+}
+
+pub fn estimate_actions_against(
+    game: &BatchChoiceGame,
+    player_index: usize,
+    strategies: &[Vec<(Action, f64)>],
+) -> Option<Vec<ActionEstimate>> {
+    let choices = game.choices_for(player_index)?.actions()?;
+    if strategies.len() != game.player_count() {
+        return None;
+    }
+
+    let opponent_strategies: Vec<Vec<(Action, f64)>> = strategies
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != player_index)
+        .map(|(_, strategy)| strategy.iter().filter(|(_, p)| *p > 0.0).cloned().collect())
+        .collect();
+    let profiles = helpers::cartesian_product(&opponent_strategies);
+
+    Some(
+        choices
+            .into_iter()
+            .map(|action| estimate(game, player_index, action, &profiles))
+            .collect(),
+    )
+}
+
+pub(crate) fn uniform_strategies(game: &BatchChoiceGame) -> Option<Vec<Vec<(Action, f64)>>> {
+    (0..game.player_count())
+        .map(|i| {
+            let actions = game.choices_for(i)?.actions()?;
+            let p = 1.0 / actions.len() as f64;
+            Some(actions.into_iter().map(|a| (a, p)).collect())
+        })
+        .collect()
+}
+
+fn estimate(
+    game: &BatchChoiceGame,
+    player_index: usize,
+    action: Action,
+    profiles: &[Vec<(Action, f64)>],
+) -> ActionEstimate {
+    let mut expected_points = 0.0;
+    let mut destruction_risk = 0.0;
+    let mut win_probability = 0.0;
+    let mut loss_probability = 0.0;
+
+    for profile in profiles {
+        let probability: f64 = profile.iter().map(|(_, p)| p).product();
+        let mut actions: Vec<Action> = profile.iter().map(|(a, _)| *a).collect();
+        actions.insert(player_index, action);
+
+        let result = resolve_round(game, &actions);
+
+        expected_points += probability * f64::from(result.points_gained[player_index]);
+        if result.destroyed[player_index] {
+            destruction_risk += probability;
+        }
+        match result.winner {
+            Some(i) if i == player_index => win_probability += probability,
+            Some(_) => loss_probability += probability,
+            None => {}
+        }
+    }
+
+    ActionEstimate {
+        action,
+        expected_points,
+        destruction_risk,
+        win_probability,
+        loss_probability,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{
+        ArsenalItem, BatchChoice, Booster, Character, DequeueChoice, Move, PointsAgainst,
+    };
+    use crate::game::Config;
+
+    fn action_phase_game(config: Config) -> BatchChoiceGame {
+        action_phase_game_with(config, Character::Samurai, Booster::Atlas)
+    }
+
+    fn action_phase_game_with(
+        config: Config,
+        opponent: Character,
+        opponent_booster: Booster,
+    ) -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::new(config);
+        game.choose(BatchChoice::Characters(vec![Character::Ninja, opponent]))
+            .unwrap();
+        game.choose(BatchChoice::Boosters(vec![
+            Booster::Shadow,
+            opponent_booster,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        game
+    }
+
+    fn estimate_of(estimates: &[ActionEstimate], action: Action) -> &ActionEstimate {
+        estimates.iter().find(|e| e.action == action).unwrap()
+    }
+
+    #[test]
+    fn estimate_actions_returns_none_outside_action_phase() {
+        assert!(estimate_actions(&BatchChoiceGame::default(), 0).is_none());
+    }
+
+    #[test]
+    fn estimate_actions_covers_every_legal_action() {
+        let game = action_phase_game(Config::default());
+        let estimates = estimate_actions(&game, 0).unwrap();
+        assert_eq!(
+            game.choices_for(0).unwrap().actions().unwrap(),
+            estimates.iter().map(|e| e.action).collect::<Vec<Action>>()
+        );
+    }
+
+    #[test]
+    fn pure_strategy_gives_exact_points() {
+        let game = action_phase_game(Config::default());
+        let lightning = Action::Move(Move::Lightning);
+        let mut strategies = uniform_strategies(&game).unwrap();
+        strategies[1] = vec![(lightning, 1.0)];
+        let estimates = estimate_actions_against(&game, 0, &strategies).unwrap();
+
+        for estimate in &estimates {
+            assert_eq!(
+                f64::from(estimate.action.points_against(&lightning)),
+                estimate.expected_points
+            );
+        }
+    }
+
+    #[test]
+    fn single_use_move_is_always_destroyed() {
+        let game = action_phase_game_with(Config::default(), Character::Clown, Booster::Backwards);
+        let estimates = estimate_actions(&game, 1).unwrap();
+        let acid_spray = estimate_of(&estimates, Action::Move(Move::AcidSpray));
+        assert_eq!(1.0, acid_spray.destruction_risk);
+    }
+
+    #[test]
+    fn destruction_risk_is_chance_of_destructive_opponent_move() {
+        let game = action_phase_game_with(Config::default(), Character::Clown, Booster::Backwards);
+        let opponent_actions = game.choices_for(1).unwrap().actions().unwrap();
+        let estimates = estimate_actions(&game, 0).unwrap();
+        let fireball = estimate_of(&estimates, Action::Move(Move::ShadowFireball));
+        assert_eq!(
+            1.0 / opponent_actions.len() as f64,
+            fireball.destruction_risk
+        );
+    }
+
+    #[test]
+    fn overshoot_is_deducted() {
+        let game = action_phase_game(Config {
+            points_to_win: 2,
+            ..Config::default()
+        });
+        let current = game.points(0).unwrap();
+        for estimate in estimate_actions(&game, 0).unwrap() {
+            assert!(estimate.expected_points <= f64::from(2 - current));
+        }
+    }
+
+    #[test]
+    fn win_and_loss_probabilities_match_game_result() {
+        let game = action_phase_game(Config {
+            points_to_win: 1,
+            ..Config::default()
+        });
+        let lightning = Action::Move(Move::Lightning);
+        let mut strategies = uniform_strategies(&game).unwrap();
+        strategies[1] = vec![(lightning, 1.0)];
+        let estimates = estimate_actions_against(&game, 0, &strategies).unwrap();
+
+        for estimate in &estimates {
+            let mut next = game.clone();
+            next.choose(BatchChoice::Actions(vec![estimate.action, lightning]))
+                .unwrap();
+            let (win, loss) = match next.winner_index() {
+                Some(0) => (1.0, 0.0),
+                Some(_) => (0.0, 1.0),
+                None => (0.0, 0.0),
+            };
+            assert_eq!(win, estimate.win_probability);
+            assert_eq!(loss, estimate.loss_probability);
+        }
+        assert!(estimates.iter().any(|e| e.win_probability == 1.0));
+    }
+
+    #[test]
+    fn mismatched_strategies_are_rejected() {
+        let game = action_phase_game(Config::default());
+        assert!(estimate_actions_against(&game, 0, &[]).is_none());
+    }
+}
@@ -0,0 +1,8 @@
+mod expected_points;
+pub(crate) use expected_points::resolve_round;
+pub use expected_points::{estimate_actions, estimate_actions_against, ActionEstimate};
+mod win_probability;
+pub use win_probability::{
+    estimate_win_probabilities, estimate_win_probabilities_with, win_probability_timeline,
+    WinProbability,
+};
@@ -0,0 +1,174 @@
+use crate::agents::{Agent, Driver};
+use crate::game::BatchChoiceGame;
+use crate::random::Prng;
+use crate::simulation::Replay;
+
+const Z_95: f64 = 1.96;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WinProbability {
+    pub estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl WinProbability {
+    fn from_wins(wins: u32, rollouts: u32) -> WinProbability {
+        if rollouts == 0 {
+            return WinProbability {
+                estimate: 0.0,
+                lower: 0.0,
+                upper: 1.0,
+            };
+        }
+
+        let n = f64::from(rollouts);
+        let p = f64::from(wins) / n;
+        let z2 = Z_95 * Z_95;
+        let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let margin = Z_95 / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+        WinProbability {
+            estimate: p,
+            lower: (center - margin).max(0.0),
+            upper: (center + margin).min(1.0),
+        }
+    }
+}
+
+pub fn estimate_win_probabilities(
+    game: &BatchChoiceGame,
+    rollouts: u32,
+    seed: u64,
+) -> Vec<WinProbability> {
+    let mut rng = Prng::new(seed);
+    let mut wins = vec![0; game.player_count()];
+
+    for _ in 0..rollouts {
+        let mut game = game.clone();
+        while let Some(choice) = game.choices().random(&mut rng) {
+            game.choose(choice)
+                .expect("random choices are always legal");
+        }
+        if let Some(winner) = game.winner_index() {
+            wins[winner] += 1;
+        }
+    }
+
+    to_probabilities(&wins, rollouts)
+}
+
+pub fn estimate_win_probabilities_with<A: Agent>(
+    game: &BatchChoiceGame,
+    agents: Vec<A>,
+    rollouts: u32,
+) -> Result<(Vec<WinProbability>, Vec<A>), ()> {
+    let mut agents = agents;
+    let mut wins = vec![0; game.player_count()];
+
+    for _ in 0..rollouts {
+        let mut driver = Driver::from_game(game.clone(), agents)?;
+        driver.run()?;
+        if let Some(winner) = driver.game().winner_index() {
+            wins[winner] += 1;
+        }
+        agents = driver.into_agents();
+    }
+
+    Ok((to_probabilities(&wins, rollouts), agents))
+}
+
+pub fn win_probability_timeline(
+    replay: &Replay,
+    rollouts: u32,
+    seed: u64,
+) -> Result<Vec<Vec<WinProbability>>, ()> {
+    let mut game = BatchChoiceGame::new(replay.config.clone());
+    let mut timeline = vec![estimate_win_probabilities(&game, rollouts, seed)];
+    for choice in &replay.choices {
+        game.choose(choice.clone())?;
+        timeline.push(estimate_win_probabilities(&game, rollouts, seed));
+    }
+    Ok(timeline)
+}
+
+fn to_probabilities(wins: &[u32], rollouts: u32) -> Vec<WinProbability> {
+    wins.iter()
+        .map(|&w| WinProbability::from_wins(w, rollouts))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{FirstLegalAgent, GreedyAgent, RandomAgent};
+    use crate::game::Config;
+    use crate::simulation::simulate;
+
+    fn limited_config() -> Config {
+        Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn estimates_are_within_intervals() {
+        let probabilities = estimate_win_probabilities(&BatchChoiceGame::default(), 200, 0);
+        assert_eq!(2, probabilities.len());
+        for p in probabilities {
+            assert!(p.lower <= p.estimate && p.estimate <= p.upper);
+            assert!(0.0 <= p.lower && p.upper <= 1.0);
+        }
+    }
+
+    #[test]
+    fn estimates_are_reproducible() {
+        let game = BatchChoiceGame::default();
+        assert_eq!(
+            estimate_win_probabilities(&game, 50, 9),
+            estimate_win_probabilities(&game, 50, 9)
+        );
+    }
+
+    #[test]
+    fn finished_game_is_certain() {
+        let replay = simulate(limited_config(), 4).unwrap();
+        let game = replay.game().unwrap();
+        let probabilities = estimate_win_probabilities(&game, 10, 0);
+        if let Some(winner) = game.winner_index() {
+            assert_eq!(1.0, probabilities[winner].estimate);
+        }
+    }
+
+    #[test]
+    fn interval_narrows_with_more_rollouts() {
+        let few = WinProbability::from_wins(5, 10);
+        let many = WinProbability::from_wins(500, 1000);
+        assert!(many.upper - many.lower < few.upper - few.lower);
+    }
+
+    #[test]
+    fn agent_rollouts_favor_stronger_agent() {
+        let agents: Vec<Box<dyn Agent>> =
+            vec![Box::new(GreedyAgent), Box::new(RandomAgent::new(0))];
+        let (probabilities, agents) =
+            estimate_win_probabilities_with(&BatchChoiceGame::new(limited_config()), agents, 50)
+                .unwrap();
+        assert_eq!(2, agents.len());
+        assert!(probabilities[0].estimate > probabilities[1].estimate);
+    }
+
+    #[test]
+    fn agent_rollouts_require_one_agent_per_player() {
+        let game = BatchChoiceGame::new(limited_config());
+        assert!(estimate_win_probabilities_with(&game, vec![FirstLegalAgent], 1).is_err());
+    }
+
+    #[test]
+    fn timeline_has_entry_per_choice() {
+        let replay = simulate(limited_config(), 2).unwrap();
+        let timeline = win_probability_timeline(&replay, 5, 0).unwrap();
+        assert_eq!(replay.choices.len() + 1, timeline.len());
+    }
+}
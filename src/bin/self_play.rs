@@ -0,0 +1,127 @@
+use nzscq::agents::{
+    Agent, FirstLegalAgent, GreedyAgent, MctsAgent, MctsConfig, ModelingAgent, OpponentModel,
+    RandomAgent,
+};
+use nzscq::simulation::{self_play, RecordFormat, SelfPlayConfig};
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process;
+
+const USAGE: &str = "usage: self_play [--games N] [--threads N] [--seed N] \
+[--format jsonl|binary] [--agents NAME,NAME] [--max-state-repetitions N] \
+[--max-rounds-without-progress N] [--out PATH]
+agents: random, first-legal, greedy, modeling, mcts";
+
+struct Args {
+    config: SelfPlayConfig,
+    agents: Vec<String>,
+    out: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        config: SelfPlayConfig::default(),
+        agents: vec!["random".to_string(), "random".to_string()],
+        out: None,
+    };
+    let mut raw = env::args().skip(1);
+
+    while let Some(flag) = raw.next() {
+        let mut value = || raw.next().ok_or(format!("missing value for {}", flag));
+        match &flag[..] {
+            "--games" => args.config.games = parse_number(&value()?)?,
+            "--threads" => args.config.threads = parse_number(&value()?)?,
+            "--seed" => args.config.seed = parse_number(&value()?)?,
+            "--format" => {
+                args.config.format = match &value()?[..] {
+                    "jsonl" => RecordFormat::Jsonl,
+                    "binary" => RecordFormat::Binary,
+                    other => return Err(format!("unknown format: {}", other)),
+                }
+            }
+            "--agents" => args.agents = value()?.split(',').map(str::to_string).collect(),
+            "--max-state-repetitions" => {
+                args.config.game.max_state_repetitions = Some(parse_number(&value()?)?)
+            }
+            "--max-rounds-without-progress" => {
+                args.config.game.max_rounds_without_progress = Some(parse_number(&value()?)?)
+            }
+            "--out" => args.out = Some(value()?),
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    for name in &args.agents {
+        make_agent(name, 0)?;
+    }
+    args.config.game.player_count = args.agents.len() as u8;
+    Ok(args)
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid number: {}", value))
+}
+
+fn make_agent(name: &str, seed: u64) -> Result<Box<dyn Agent>, String> {
+    let agent: Box<dyn Agent> = match name {
+        "random" => Box::new(RandomAgent::new(seed)),
+        "first-legal" => Box::new(FirstLegalAgent),
+        "greedy" => Box::new(GreedyAgent),
+        "modeling" => Box::new(ModelingAgent::new(OpponentModel::default(), seed)),
+        "mcts" => Box::new(MctsAgent::new(MctsConfig {
+            iterations: 200,
+            seed,
+            ..MctsConfig::default()
+        })),
+        other => return Err(format!("unknown agent: {}", other)),
+    };
+    Ok(agent)
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let writer: Box<dyn Write> = match &args.out {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    let names = &args.agents;
+    let summary = self_play(
+        &args.config,
+        |seed| {
+            names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    make_agent(name, seed.wrapping_mul(31).wrapping_add(i as u64))
+                        .expect("agent names were validated")
+                })
+                .collect()
+        },
+        writer,
+    )?;
+
+    eprintln!(
+        "games: {}, records: {}, wins: {:?}, draws: {}",
+        summary.games, summary.records, summary.wins, summary.draws
+    );
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = run(args) {
+        eprintln!("self_play: {}", e);
+        process::exit(1);
+    }
+}
@@ -7,7 +7,30 @@ pub enum Action {
     Concede,
 }
 
+const MOVE_COUNT: u8 = 29;
+const CONCEDE_INDEX: u8 = 2 * MOVE_COUNT;
+
 impl Action {
+    pub fn to_index(self) -> u8 {
+        match self {
+            Action::Move(m) => m.to_index(),
+            Action::Mirror(m) => MOVE_COUNT + m.to_index(),
+            Action::Concede => CONCEDE_INDEX,
+        }
+    }
+
+    pub fn from_index(index: u8) -> Option<Action> {
+        if index < MOVE_COUNT {
+            Move::from_index(index).map(Action::Move)
+        } else if index < CONCEDE_INDEX {
+            Move::from_index(index - MOVE_COUNT).map(Action::Mirror)
+        } else if index == CONCEDE_INDEX {
+            Some(Action::Concede)
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn which_destroyed(actions: &[Action]) -> Vec<bool> {
         let was_destructive_used = actions.iter().any(|a| a.is_destructive());
 
@@ -43,9 +66,9 @@ impl PointsAgainst for Action {
     }
 }
 
-impl Into<Option<ArsenalItem>> for Action {
-    fn into(self) -> Option<ArsenalItem> {
-        match self {
+impl From<Action> for Option<ArsenalItem> {
+    fn from(action: Action) -> Option<ArsenalItem> {
+        match action {
             Action::Mirror(_) => Some(ArsenalItem::Mirror),
             Action::Move(m) => Some(ArsenalItem::Move(m)),
             Action::Concede => None,
@@ -53,12 +76,39 @@ impl Into<Option<ArsenalItem>> for Action {
     }
 }
 
-impl Into<Option<Move>> for Action {
-    fn into(self) -> Option<Move> {
-        match self {
+impl From<Action> for Option<Move> {
+    fn from(action: Action) -> Option<Move> {
+        match action {
             Action::Mirror(m) => Some(m),
             Action::Move(m) => Some(m),
             Action::Concede => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_index_is_offset_by_move_count() {
+        assert_eq!(29, Action::Mirror(Move::Kick).to_index());
+        assert_eq!(
+            Some(Action::Mirror(Move::BigHairyDeal)),
+            Action::from_index(57)
+        );
+    }
+
+    #[test]
+    fn concede_is_last_index() {
+        assert_eq!(58, Action::Concede.to_index());
+        assert_eq!(None, Action::from_index(59));
+    }
+
+    #[test]
+    fn index_round_trips() {
+        for index in 0..59 {
+            assert_eq!(index, Action::from_index(index).unwrap().to_index());
+        }
+    }
+}
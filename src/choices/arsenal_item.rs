@@ -1,4 +1,6 @@
-use super::{Action, Move};
+use super::Move;
+
+use std::fmt::{self, Display, Formatter};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ArsenalItem {
@@ -6,20 +8,49 @@ pub enum ArsenalItem {
     Move(Move),
 }
 
+const MIRROR_INDEX: u8 = 29;
+
 impl ArsenalItem {
-    pub(crate) fn as_move_action(self) -> Option<Action> {
-        if let ArsenalItem::Move(m) = self {
-            Some(Action::Move(m))
-        } else {
-            None
+    pub fn to_index(self) -> u8 {
+        match self {
+            ArsenalItem::Move(m) => m.to_index(),
+            ArsenalItem::Mirror => MIRROR_INDEX,
         }
     }
 
-    pub(crate) fn as_mirror_action(self) -> Option<Action> {
-        if let ArsenalItem::Move(m) = self {
-            Some(Action::Mirror(m))
+    pub fn from_index(index: u8) -> Option<ArsenalItem> {
+        if index == MIRROR_INDEX {
+            Some(ArsenalItem::Mirror)
         } else {
-            None
+            Move::from_index(index).map(ArsenalItem::Move)
+        }
+    }
+}
+
+impl Display for ArsenalItem {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ArsenalItem::Mirror => write!(f, "Mirror"),
+            ArsenalItem::Move(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_comes_after_every_move() {
+        assert_eq!(29, ArsenalItem::Mirror.to_index());
+        assert_eq!(Some(ArsenalItem::Mirror), ArsenalItem::from_index(29));
+        assert_eq!(None, ArsenalItem::from_index(30));
+    }
+
+    #[test]
+    fn index_round_trips() {
+        for index in 0..30 {
+            assert_eq!(index, ArsenalItem::from_index(index).unwrap().to_index());
         }
     }
 }
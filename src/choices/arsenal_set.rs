@@ -0,0 +1,318 @@
+use super::{ArsenalItem, Move};
+
+use std::fmt::{self, Debug, Formatter};
+use std::iter::FromIterator;
+
+const MOVE_MASK: u32 = (1 << 29) - 1;
+const ARSENAL_MASK: u32 = (1 << 30) - 1;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MoveSet(u32);
+
+impl MoveSet {
+    pub fn new() -> Self {
+        MoveSet(0)
+    }
+
+    pub fn all() -> Self {
+        MoveSet(MOVE_MASK)
+    }
+
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !MOVE_MASK == 0 {
+            Some(MoveSet(bits))
+        } else {
+            None
+        }
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, m: Move) -> bool {
+        self.0 & bit(m.to_index()) != 0
+    }
+
+    pub fn insert(&mut self, m: Move) -> bool {
+        let was_absent = !self.contains(m);
+        self.0 |= bit(m.to_index());
+        was_absent
+    }
+
+    pub fn remove(&mut self, m: Move) -> bool {
+        let was_present = self.contains(m);
+        self.0 &= !bit(m.to_index());
+        was_present
+    }
+
+    pub fn union(self, other: MoveSet) -> MoveSet {
+        MoveSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: MoveSet) -> MoveSet {
+        MoveSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: MoveSet) -> MoveSet {
+        MoveSet(self.0 & !other.0)
+    }
+
+    pub fn iter(self) -> MoveSetIter {
+        MoveSetIter(self.0)
+    }
+}
+
+impl Debug for MoveSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Move> for MoveSet {
+    fn from_iter<I: IntoIterator<Item = Move>>(iter: I) -> Self {
+        let mut set = MoveSet::new();
+        for m in iter {
+            set.insert(m);
+        }
+        set
+    }
+}
+
+impl IntoIterator for MoveSet {
+    type Item = Move;
+    type IntoIter = MoveSetIter;
+
+    fn into_iter(self) -> MoveSetIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoveSetIter(u32);
+
+impl Iterator for MoveSetIter {
+    type Item = Move;
+
+    fn next(&mut self) -> Option<Move> {
+        pop_lowest(&mut self.0).and_then(Move::from_index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for MoveSetIter {}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ArsenalSet(u32);
+
+impl ArsenalSet {
+    pub fn new() -> Self {
+        ArsenalSet(0)
+    }
+
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !ARSENAL_MASK == 0 {
+            Some(ArsenalSet(bits))
+        } else {
+            None
+        }
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, item: ArsenalItem) -> bool {
+        self.0 & bit(item.to_index()) != 0
+    }
+
+    pub fn has_mirror(self) -> bool {
+        self.contains(ArsenalItem::Mirror)
+    }
+
+    pub fn insert(&mut self, item: ArsenalItem) -> bool {
+        let was_absent = !self.contains(item);
+        self.0 |= bit(item.to_index());
+        was_absent
+    }
+
+    pub fn remove(&mut self, item: ArsenalItem) -> bool {
+        let was_present = self.contains(item);
+        self.0 &= !bit(item.to_index());
+        was_present
+    }
+
+    pub fn moves(self) -> MoveSet {
+        MoveSet(self.0 & MOVE_MASK)
+    }
+
+    pub fn iter(self) -> ArsenalSetIter {
+        ArsenalSetIter(self.0)
+    }
+
+    pub fn to_vec(self) -> Vec<ArsenalItem> {
+        self.iter().collect()
+    }
+}
+
+impl Debug for ArsenalSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<MoveSet> for ArsenalSet {
+    fn from(moves: MoveSet) -> ArsenalSet {
+        ArsenalSet(moves.0)
+    }
+}
+
+impl<'a> From<&'a [ArsenalItem]> for ArsenalSet {
+    fn from(items: &'a [ArsenalItem]) -> ArsenalSet {
+        items.iter().copied().collect()
+    }
+}
+
+impl FromIterator<ArsenalItem> for ArsenalSet {
+    fn from_iter<I: IntoIterator<Item = ArsenalItem>>(iter: I) -> Self {
+        let mut set = ArsenalSet::new();
+        for item in iter {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl IntoIterator for ArsenalSet {
+    type Item = ArsenalItem;
+    type IntoIter = ArsenalSetIter;
+
+    fn into_iter(self) -> ArsenalSetIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArsenalSetIter(u32);
+
+impl Iterator for ArsenalSetIter {
+    type Item = ArsenalItem;
+
+    fn next(&mut self) -> Option<ArsenalItem> {
+        pop_lowest(&mut self.0).and_then(ArsenalItem::from_index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for ArsenalSetIter {}
+
+fn bit(index: u8) -> u32 {
+    1 << index
+}
+
+fn pop_lowest(bits: &mut u32) -> Option<u8> {
+    if *bits == 0 {
+        None
+    } else {
+        let index = bits.trailing_zeros() as u8;
+        *bits &= *bits - 1;
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove_report_changes() {
+        let mut set = ArsenalSet::new();
+        assert!(set.insert(ArsenalItem::Mirror));
+        assert!(!set.insert(ArsenalItem::Mirror));
+        assert!(set.insert(ArsenalItem::Move(Move::Kick)));
+        assert_eq!(2, set.len());
+        assert!(set.remove(ArsenalItem::Mirror));
+        assert!(!set.remove(ArsenalItem::Mirror));
+        assert!(!set.has_mirror());
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn iteration_is_in_index_order() {
+        let set: ArsenalSet = vec![
+            ArsenalItem::Mirror,
+            ArsenalItem::Move(Move::Zap),
+            ArsenalItem::Move(Move::Kick),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            vec![
+                ArsenalItem::Move(Move::Kick),
+                ArsenalItem::Move(Move::Zap),
+                ArsenalItem::Mirror
+            ],
+            set.to_vec()
+        );
+        assert_eq!(3, set.iter().len());
+    }
+
+    #[test]
+    fn moves_excludes_mirror() {
+        let set: ArsenalSet = vec![ArsenalItem::Mirror, ArsenalItem::Move(Move::Nose)]
+            .into_iter()
+            .collect();
+        assert_eq!(vec![Move::Nose], set.moves().iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn all_moves_iterates_every_move() {
+        assert_eq!(Move::all(), MoveSet::all().iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn set_operations_work() {
+        let a: MoveSet = vec![Move::Kick, Move::Zap].into_iter().collect();
+        let b: MoveSet = vec![Move::Zap, Move::Nose].into_iter().collect();
+        assert_eq!(
+            vec![Move::Zap],
+            a.intersection(b).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![Move::Kick], a.difference(b).iter().collect::<Vec<_>>());
+        assert_eq!(3, a.union(b).len());
+    }
+
+    #[test]
+    fn from_bits_rejects_unknown_items() {
+        assert_eq!(None, MoveSet::from_bits(1 << 29));
+        assert_eq!(
+            Some(ArsenalItem::Mirror),
+            ArsenalSet::from_bits(1 << 29).unwrap().iter().next()
+        );
+        assert_eq!(None, ArsenalSet::from_bits(1 << 30));
+    }
+}
@@ -1,4 +1,5 @@
-use super::{Action, Booster, Character, DequeueChoice};
+use super::{Action, Booster, Character, Choice, DequeueChoice};
+use crate::random::Prng;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BatchChoice {
@@ -9,6 +10,49 @@ pub enum BatchChoice {
 }
 
 impl BatchChoice {
+    pub fn from_choices(choices: Vec<Choice>) -> Result<BatchChoice, ()> {
+        fn collect<T>(choices: Vec<Choice>, f: impl Fn(Choice) -> Option<T>) -> Result<Vec<T>, ()> {
+            choices.into_iter().map(|c| f(c).ok_or(())).collect()
+        }
+
+        match choices.first() {
+            Some(Choice::Character(_)) => collect(choices, |c| match c {
+                Choice::Character(character) => Some(character),
+                _ => None,
+            })
+            .map(BatchChoice::Characters),
+            Some(Choice::Booster(_)) => collect(choices, |c| match c {
+                Choice::Booster(booster) => Some(booster),
+                _ => None,
+            })
+            .map(BatchChoice::Boosters),
+            Some(Choice::DequeueChoice(_)) => collect(choices, |c| match c {
+                Choice::DequeueChoice(dequeue_choice) => Some(dequeue_choice),
+                _ => None,
+            })
+            .map(BatchChoice::DequeueChoices),
+            Some(Choice::Action(_)) => collect(choices, |c| match c {
+                Choice::Action(action) => Some(action),
+                _ => None,
+            })
+            .map(BatchChoice::Actions),
+            None => Err(()),
+        }
+    }
+
+    pub fn to_choices(&self) -> Vec<Choice> {
+        fn convert<T: Copy + Into<Choice>>(choices: &[T]) -> Vec<Choice> {
+            choices.iter().map(|&choice| choice.into()).collect()
+        }
+
+        match self {
+            BatchChoice::Characters(characters) => convert(characters),
+            BatchChoice::Boosters(boosters) => convert(boosters),
+            BatchChoice::DequeueChoices(dequeue_choices) => convert(dequeue_choices),
+            BatchChoice::Actions(actions) => convert(actions),
+        }
+    }
+
     pub fn len(&self) -> usize {
         match self {
             BatchChoice::Characters(characters) => characters.len(),
@@ -69,12 +113,110 @@ impl BatchChoices {
             None
         }
     }
+
+    pub fn to_choices(&self) -> Vec<Vec<Choice>> {
+        fn convert<T: Copy + Into<Choice>>(choices: &[Vec<T>]) -> Vec<Vec<Choice>> {
+            choices
+                .iter()
+                .map(|c| c.iter().map(|&choice| choice.into()).collect())
+                .collect()
+        }
+
+        match self {
+            BatchChoices::Characters(characters) => convert(characters),
+            BatchChoices::Boosters(boosters) => convert(boosters),
+            BatchChoices::DequeueChoices(dequeue_choices) => convert(dequeue_choices),
+            BatchChoices::Actions(actions) => convert(actions),
+            BatchChoices::None => vec![],
+        }
+    }
+
+    pub fn random(&self, rng: &mut Prng) -> Option<BatchChoice> {
+        fn pick<T: Copy>(rng: &mut Prng, choices: &[Vec<T>]) -> Vec<T> {
+            choices.iter().map(|c| c[rng.below(c.len())]).collect()
+        }
+
+        match self {
+            BatchChoices::Characters(characters) => {
+                Some(BatchChoice::Characters(pick(rng, characters)))
+            }
+            BatchChoices::Boosters(boosters) => Some(BatchChoice::Boosters(pick(rng, boosters))),
+            BatchChoices::DequeueChoices(dequeue_choices) => {
+                Some(BatchChoice::DequeueChoices(pick(rng, dequeue_choices)))
+            }
+            BatchChoices::Actions(actions) => Some(BatchChoice::Actions(pick(rng, actions))),
+            BatchChoices::None => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn from_choices_works() {
+        assert_eq!(
+            Ok(BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Clown
+            ])),
+            BatchChoice::from_choices(vec![
+                Choice::Character(Character::Ninja),
+                Choice::Character(Character::Clown),
+            ])
+        );
+    }
+
+    #[test]
+    fn from_choices_fails_if_choice_types_are_mixed() {
+        assert!(BatchChoice::from_choices(vec![
+            Choice::Character(Character::Ninja),
+            Choice::Booster(Booster::None),
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn from_choices_fails_if_empty() {
+        assert!(BatchChoice::from_choices(vec![]).is_err());
+    }
+
+    #[test]
+    fn to_choices_works() {
+        let choices = BatchChoices::Boosters(vec![vec![Booster::None], vec![Booster::Atlas]]);
+        assert_eq!(
+            vec![
+                vec![Choice::Booster(Booster::None)],
+                vec![Choice::Booster(Booster::Atlas)]
+            ],
+            choices.to_choices()
+        );
+    }
+
+    #[test]
+    fn random_picks_a_legal_choice_for_each_player() {
+        let choices = BatchChoices::Boosters(vec![
+            Character::Ninja.boosters(),
+            Character::Clown.boosters(),
+        ]);
+        let mut rng = Prng::new(0);
+        for _ in 0..20 {
+            let boosters = choices.random(&mut rng).unwrap();
+            if let BatchChoice::Boosters(boosters) = boosters {
+                assert!(Character::Ninja.boosters().contains(&boosters[0]));
+                assert!(Character::Clown.boosters().contains(&boosters[1]));
+            } else {
+                panic!("Wrong variant.");
+            }
+        }
+    }
+
+    #[test]
+    fn random_returns_none_if_there_are_no_choices() {
+        assert_eq!(None, BatchChoices::None.random(&mut Prng::new(0)));
+    }
+
     #[test]
     fn characters_returns_some_if_variant_is_characters() {
         let choices = BatchChoices::Characters(vec![]);
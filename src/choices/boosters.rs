@@ -1,35 +1,38 @@
 use super::Move;
 use crate::helpers;
 
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Booster {
-    Shadow,
-    Speedy,
-    Regenerative,
-    ZombieCorps,
-    Atlas,
-    Strong,
-    Backwards,
-    Moustachio,
-    None,
+    Shadow = 0,
+    Speedy = 1,
+    Regenerative = 2,
+    ZombieCorps = 3,
+    Atlas = 4,
+    Strong = 5,
+    Backwards = 6,
+    Moustachio = 7,
+    None = 8,
 }
 
 impl Booster {
     pub fn all() -> Vec<Booster> {
-        vec![
-            Booster::Shadow,
-            Booster::Speedy,
-            Booster::Regenerative,
-            Booster::ZombieCorps,
-            Booster::Atlas,
-            Booster::Strong,
-            Booster::Backwards,
-            Booster::Moustachio,
-            Booster::None,
-        ]
+        BOOSTERS.to_vec()
+    }
+
+    pub fn iter() -> impl Iterator<Item = Booster> + Clone {
+        BOOSTERS.iter().copied()
+    }
+
+    pub fn to_index(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_index(index: u8) -> Option<Booster> {
+        BOOSTERS.get(usize::from(index)).copied()
     }
 
     pub fn moves(self) -> Vec<Move> {
@@ -73,6 +76,14 @@ impl FromStr for Booster {
     }
 }
 
+impl TryFrom<u8> for Booster {
+    type Error = ();
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Booster::from_index(index).ok_or(())
+    }
+}
+
 impl Display for Booster {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let string = match self {
@@ -91,6 +102,18 @@ impl Display for Booster {
     }
 }
 
+const BOOSTERS: [Booster; 9] = [
+    Booster::Shadow,
+    Booster::Speedy,
+    Booster::Regenerative,
+    Booster::ZombieCorps,
+    Booster::Atlas,
+    Booster::Strong,
+    Booster::Backwards,
+    Booster::Moustachio,
+    Booster::None,
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +149,13 @@ mod tests {
         assert!(!replaced.contains(&Move::Smash));
         assert!(replaced.contains(&Move::StrongSmash));
     }
+
+    #[test]
+    fn index_round_trips() {
+        for (i, booster) in Booster::all().into_iter().enumerate() {
+            assert_eq!(i as u8, booster.to_index());
+            assert_eq!(Ok(booster), Booster::try_from(i as u8));
+        }
+        assert_eq!(None, Booster::from_index(Booster::all().len() as u8));
+    }
 }
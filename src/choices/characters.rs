@@ -1,6 +1,7 @@
 use super::{Booster, Move, PointsAgainst};
 use crate::helpers;
 
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
@@ -14,12 +15,19 @@ pub enum Character {
 
 impl Character {
     pub fn all() -> Vec<Character> {
-        vec![
-            Character::Ninja,
-            Character::Zombie,
-            Character::Samurai,
-            Character::Clown,
-        ]
+        CHARACTERS.to_vec()
+    }
+
+    pub fn iter() -> impl Iterator<Item = Character> + Clone {
+        CHARACTERS.iter().copied()
+    }
+
+    pub fn to_index(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_index(index: u8) -> Option<Character> {
+        CHARACTERS.get(usize::from(index)).copied()
     }
 
     pub fn moves(self) -> Vec<Move> {
@@ -63,6 +71,14 @@ impl FromStr for Character {
     }
 }
 
+impl TryFrom<u8> for Character {
+    type Error = ();
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Character::from_index(index).ok_or(())
+    }
+}
+
 impl Display for Character {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let string = match self {
@@ -76,6 +92,13 @@ impl Display for Character {
     }
 }
 
+const CHARACTERS: [Character; 4] = [
+    Character::Ninja,
+    Character::Zombie,
+    Character::Samurai,
+    Character::Clown,
+];
+
 const CHARACTER_HEADSTARTS: [u8; 4 * 4] = [0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0];
 
 #[cfg(test)]
@@ -124,4 +147,13 @@ mod tests {
         assert_eq!(Character::Ninja.points_against(&Character::Zombie), 0);
         assert_eq!(Character::Zombie.points_against(&Character::Ninja), 0);
     }
+
+    #[test]
+    fn index_round_trips() {
+        for (i, character) in Character::all().into_iter().enumerate() {
+            assert_eq!(i as u8, character.to_index());
+            assert_eq!(Ok(character), Character::try_from(i as u8));
+        }
+        assert_eq!(None, Character::from_index(Character::all().len() as u8));
+    }
 }
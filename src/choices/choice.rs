@@ -0,0 +1,200 @@
+use super::{Action, Booster, Character, DequeueChoice};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Choice {
+    Character(Character),
+    Booster(Booster),
+    DequeueChoice(DequeueChoice),
+    Action(Action),
+}
+
+impl Choice {
+    pub fn character(self) -> Option<Character> {
+        if let Choice::Character(character) = self {
+            Some(character)
+        } else {
+            None
+        }
+    }
+
+    pub fn booster(self) -> Option<Booster> {
+        if let Choice::Booster(booster) = self {
+            Some(booster)
+        } else {
+            None
+        }
+    }
+
+    pub fn dequeue_choice(self) -> Option<DequeueChoice> {
+        if let Choice::DequeueChoice(dequeue_choice) = self {
+            Some(dequeue_choice)
+        } else {
+            None
+        }
+    }
+
+    pub fn action(self) -> Option<Action> {
+        if let Choice::Action(action) = self {
+            Some(action)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Character> for Choice {
+    fn from(character: Character) -> Choice {
+        Choice::Character(character)
+    }
+}
+
+impl From<Booster> for Choice {
+    fn from(booster: Booster) -> Choice {
+        Choice::Booster(booster)
+    }
+}
+
+impl From<DequeueChoice> for Choice {
+    fn from(dequeue_choice: DequeueChoice) -> Choice {
+        Choice::DequeueChoice(dequeue_choice)
+    }
+}
+
+impl From<Action> for Choice {
+    fn from(action: Action) -> Choice {
+        Choice::Action(action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Choices {
+    Characters(Vec<Character>),
+    Boosters(Vec<Booster>),
+    DequeueChoices(Vec<DequeueChoice>),
+    Actions(Vec<Action>),
+    None,
+}
+
+impl Choices {
+    pub fn len(&self) -> usize {
+        match self {
+            Choices::Characters(characters) => characters.len(),
+            Choices::Boosters(boosters) => boosters.len(),
+            Choices::DequeueChoices(dequeue_choices) => dequeue_choices.len(),
+            Choices::Actions(actions) => actions.len(),
+            Choices::None => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, choice: &Choice) -> bool {
+        match (self, choice) {
+            (Choices::Characters(characters), Choice::Character(c)) => characters.contains(c),
+            (Choices::Boosters(boosters), Choice::Booster(b)) => boosters.contains(b),
+            (Choices::DequeueChoices(dequeue_choices), Choice::DequeueChoice(d)) => {
+                dequeue_choices.contains(d)
+            }
+            (Choices::Actions(actions), Choice::Action(a)) => actions.contains(a),
+            _ => false,
+        }
+    }
+
+    pub fn characters(self) -> Option<Vec<Character>> {
+        if let Choices::Characters(characters) = self {
+            Some(characters)
+        } else {
+            None
+        }
+    }
+
+    pub fn boosters(self) -> Option<Vec<Booster>> {
+        if let Choices::Boosters(boosters) = self {
+            Some(boosters)
+        } else {
+            None
+        }
+    }
+
+    pub fn dequeue_choices(self) -> Option<Vec<DequeueChoice>> {
+        if let Choices::DequeueChoices(dequeue_choices) = self {
+            Some(dequeue_choices)
+        } else {
+            None
+        }
+    }
+
+    pub fn actions(self) -> Option<Vec<Action>> {
+        if let Choices::Actions(actions) = self {
+            Some(actions)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn character_returns_some_if_variant_is_character() {
+        assert_eq!(
+            Some(Character::Clown),
+            Choice::Character(Character::Clown).character()
+        );
+    }
+
+    #[test]
+    fn character_returns_none_if_variant_is_not_character() {
+        assert_eq!(None, Choice::Booster(Booster::None).character());
+    }
+
+    #[test]
+    fn action_returns_some_if_variant_is_action() {
+        assert_eq!(
+            Some(Action::Concede),
+            Choice::Action(Action::Concede).action()
+        );
+    }
+
+    #[test]
+    fn action_returns_none_if_variant_is_not_action() {
+        assert_eq!(None, Choice::Character(Character::Ninja).action());
+    }
+
+    #[test]
+    fn contains_works() {
+        let choices = Choices::Characters(vec![Character::Ninja, Character::Clown]);
+        assert!(choices.contains(&Character::Ninja.into()));
+        assert!(!choices.contains(&Character::Zombie.into()));
+        assert!(!choices.contains(&Booster::None.into()));
+    }
+
+    #[test]
+    fn none_is_empty() {
+        assert!(Choices::None.is_empty());
+    }
+
+    #[test]
+    fn characters_returns_some_if_variant_is_characters() {
+        assert!(Choices::Characters(vec![]).characters().is_some());
+    }
+
+    #[test]
+    fn characters_returns_none_if_variant_is_not_characters() {
+        assert!(Choices::Boosters(vec![]).characters().is_none());
+    }
+
+    #[test]
+    fn actions_returns_some_if_variant_is_actions() {
+        assert!(Choices::Actions(vec![]).actions().is_some());
+    }
+
+    #[test]
+    fn actions_returns_none_if_variant_is_not_actions() {
+        assert!(Choices::None.actions().is_none());
+    }
+}
@@ -6,3 +6,42 @@ pub enum DequeueChoice {
     JustExit,
     Decline,
 }
+
+const JUST_EXIT_INDEX: u8 = 30;
+const DECLINE_INDEX: u8 = 31;
+
+impl DequeueChoice {
+    pub fn to_index(self) -> u8 {
+        match self {
+            DequeueChoice::DrainAndExit(item) => item.to_index(),
+            DequeueChoice::JustExit => JUST_EXIT_INDEX,
+            DequeueChoice::Decline => DECLINE_INDEX,
+        }
+    }
+
+    pub fn from_index(index: u8) -> Option<DequeueChoice> {
+        match index {
+            JUST_EXIT_INDEX => Some(DequeueChoice::JustExit),
+            DECLINE_INDEX => Some(DequeueChoice::Decline),
+            _ => ArsenalItem::from_index(index).map(DequeueChoice::DrainAndExit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decline_is_last_index() {
+        assert_eq!(31, DequeueChoice::Decline.to_index());
+        assert_eq!(None, DequeueChoice::from_index(32));
+    }
+
+    #[test]
+    fn index_round_trips() {
+        for index in 0..32 {
+            assert_eq!(index, DequeueChoice::from_index(index).unwrap().to_index());
+        }
+    }
+}
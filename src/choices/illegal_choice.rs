@@ -0,0 +1,72 @@
+use super::{ArsenalItem, Booster, Character};
+use crate::game::PhaseKind;
+
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IllegalChoice {
+    NoSuchPlayer(usize),
+    WrongPhase(PhaseKind),
+    CharacterRepeated(Character, u8),
+    BoosterUnavailable(Character, Booster),
+    NotInPool(ArsenalItem),
+    NotInArsenal(ArsenalItem),
+    ExitOccupiedAndArsenalFull,
+    ConcedeUnavailable,
+}
+
+impl Display for IllegalChoice {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            IllegalChoice::NoSuchPlayer(index) => write!(f, "No player at index {}", index),
+            IllegalChoice::WrongPhase(phase) => write!(f, "Not allowed in {:?} phase", phase),
+            IllegalChoice::CharacterRepeated(character, times) => {
+                write!(f, "{} already chosen {} times in a row", character, times)
+            }
+            IllegalChoice::BoosterUnavailable(character, booster) => {
+                write!(f, "{} not available to {}", booster, character)
+            }
+            IllegalChoice::NotInPool(item) => write!(f, "{} not in pool", item),
+            IllegalChoice::NotInArsenal(item) => write!(f, "{} not in arsenal", item),
+            IllegalChoice::ExitOccupiedAndArsenalFull => {
+                write!(f, "Exit occupied and arsenal full")
+            }
+            IllegalChoice::ConcedeUnavailable => {
+                write!(f, "Concede only allowed when no other action is")
+            }
+        }
+    }
+}
+
+pub(crate) trait ExplainIllegal<T> {
+    fn explain_illegal(&self, choice: &T) -> Option<IllegalChoice>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn character_repeated_to_string_works() {
+        assert_eq!(
+            "Ninja already chosen 3 times in a row",
+            IllegalChoice::CharacterRepeated(Character::Ninja, 3).to_string()
+        );
+    }
+
+    #[test]
+    fn not_in_arsenal_to_string_works() {
+        assert_eq!(
+            "Mirror not in arsenal",
+            IllegalChoice::NotInArsenal(ArsenalItem::Mirror).to_string()
+        );
+    }
+
+    #[test]
+    fn exit_occupied_and_arsenal_full_to_string_works() {
+        assert_eq!(
+            "Exit occupied and arsenal full",
+            IllegalChoice::ExitOccupiedAndArsenalFull.to_string()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use super::{Action, ArsenalSet, ArsenalSetIter, DequeueChoice, MoveSetIter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LegalActions {
+    moves: MoveSetIter,
+    mirrors: MoveSetIter,
+    concede: bool,
+}
+
+impl LegalActions {
+    pub(crate) fn new(arsenal: ArsenalSet, pool: ArsenalSet) -> Self {
+        let moves = arsenal.moves();
+        let mirrors = if arsenal.has_mirror() {
+            pool.moves()
+        } else {
+            Default::default()
+        };
+
+        Self {
+            moves: moves.iter(),
+            mirrors: mirrors.iter(),
+            concede: moves.is_empty() && mirrors.is_empty(),
+        }
+    }
+}
+
+impl Iterator for LegalActions {
+    type Item = Action;
+
+    fn next(&mut self) -> Option<Action> {
+        if let Some(m) = self.moves.next() {
+            Some(Action::Move(m))
+        } else if let Some(m) = self.mirrors.next() {
+            Some(Action::Mirror(m))
+        } else if self.concede {
+            self.concede = false;
+            Some(Action::Concede)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.moves.len() + self.mirrors.len() + usize::from(self.concede);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for LegalActions {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LegalDequeueChoices {
+    drainees: ArsenalSetIter,
+    just_exit: bool,
+    decline: bool,
+}
+
+impl LegalDequeueChoices {
+    pub(crate) fn new(pool: ArsenalSet, can_dequeue: bool) -> Self {
+        let pool = if can_dequeue { pool } else { ArsenalSet::new() };
+
+        Self {
+            drainees: pool.iter(),
+            just_exit: can_dequeue,
+            decline: true,
+        }
+    }
+}
+
+impl Iterator for LegalDequeueChoices {
+    type Item = DequeueChoice;
+
+    fn next(&mut self) -> Option<DequeueChoice> {
+        if let Some(item) = self.drainees.next() {
+            Some(DequeueChoice::DrainAndExit(item))
+        } else if self.just_exit {
+            self.just_exit = false;
+            Some(DequeueChoice::JustExit)
+        } else if self.decline {
+            self.decline = false;
+            Some(DequeueChoice::Decline)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.drainees.len() + usize::from(self.just_exit) + usize::from(self.decline);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for LegalDequeueChoices {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{ArsenalItem, Move};
+
+    fn set(items: &[ArsenalItem]) -> ArsenalSet {
+        items.into()
+    }
+
+    #[test]
+    fn mirror_adds_pool_moves() {
+        let arsenal = set(&[ArsenalItem::Mirror, ArsenalItem::Move(Move::Kick)]);
+        let pool = set(&[ArsenalItem::Move(Move::Zap)]);
+        let actions = LegalActions::new(arsenal, pool);
+        assert_eq!(2, actions.len());
+        assert_eq!(
+            vec![Action::Move(Move::Kick), Action::Mirror(Move::Zap)],
+            actions.collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn actions_follow_move_index_order_regardless_of_insertion_order() {
+        let arsenal = set(&[
+            ArsenalItem::Move(Move::Zap),
+            ArsenalItem::Mirror,
+            ArsenalItem::Move(Move::Kick),
+        ]);
+        let pool = set(&[
+            ArsenalItem::Move(Move::Nose),
+            ArsenalItem::Move(Move::Rampage),
+        ]);
+        assert_eq!(
+            vec![
+                Action::Move(Move::Kick),
+                Action::Move(Move::Zap),
+                Action::Mirror(Move::Rampage),
+                Action::Mirror(Move::Nose)
+            ],
+            LegalActions::new(arsenal, pool).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn concede_only_when_nothing_else() {
+        let pool = set(&[ArsenalItem::Move(Move::Zap)]);
+        assert_eq!(
+            vec![Action::Concede],
+            LegalActions::new(ArsenalSet::new(), pool).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Action::Concede],
+            LegalActions::new(set(&[ArsenalItem::Mirror]), ArsenalSet::new()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn dequeue_choices_end_with_just_exit_and_decline() {
+        let pool = set(&[ArsenalItem::Mirror]);
+        assert_eq!(
+            vec![
+                DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+                DequeueChoice::JustExit,
+                DequeueChoice::Decline
+            ],
+            LegalDequeueChoices::new(pool, true).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![DequeueChoice::Decline],
+            LegalDequeueChoices::new(pool, false).collect::<Vec<_>>()
+        );
+    }
+}
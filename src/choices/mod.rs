@@ -3,16 +3,27 @@ pub use batch_choice::{BatchChoice, BatchChoices};
 
 mod moves;
 pub use moves::Move;
+mod move_outcomes;
+pub use move_outcomes::MoveOutcomes;
 mod boosters;
 pub use boosters::Booster;
 mod characters;
 pub use characters::Character;
 mod arsenal_item;
 pub use arsenal_item::ArsenalItem;
+mod arsenal_set;
+pub use arsenal_set::{ArsenalSet, ArsenalSetIter, MoveSet, MoveSetIter};
 mod action;
 pub use action::Action;
 mod dequeue_choice;
 pub use dequeue_choice::DequeueChoice;
+mod choice;
+pub use choice::{Choice, Choices};
+mod legal_choices;
+pub use legal_choices::{LegalActions, LegalDequeueChoices};
+mod illegal_choice;
+pub(crate) use illegal_choice::ExplainIllegal;
+pub use illegal_choice::IllegalChoice;
 
 pub(crate) trait Choose<T> {
     fn choices(&self) -> Vec<T>;
@@ -28,7 +39,7 @@ where
     C: PartialEq,
 {
     fn can_choose(&self, choice: &C) -> bool {
-        self.choices().contains(&choice)
+        self.choices().contains(choice)
     }
 }
 
@@ -65,42 +76,41 @@ pub trait PointsAgainst: Sized {
 mod tests {
     use super::*;
 
-    pub enum RPS {
+    pub enum Rps {
         Rock,
         Paper,
         Scissors,
     }
 
-    impl PointsAgainst for RPS {
-        fn points_against(&self, other: &RPS) -> u8 {
+    impl PointsAgainst for Rps {
+        fn points_against(&self, other: &Rps) -> u8 {
             match (self, other) {
-                (RPS::Rock, RPS::Rock) => 0,
-                (RPS::Rock, RPS::Paper) => 0,
-                (RPS::Rock, RPS::Scissors) => 1,
+                (Rps::Rock, Rps::Rock) => 0,
+                (Rps::Rock, Rps::Paper) => 0,
+                (Rps::Rock, Rps::Scissors) => 1,
 
-                (RPS::Paper, RPS::Rock) => 1,
-                (RPS::Paper, RPS::Paper) => 0,
-                (RPS::Paper, RPS::Scissors) => 0,
+                (Rps::Paper, Rps::Rock) => 1,
+                (Rps::Paper, Rps::Paper) => 0,
+                (Rps::Paper, Rps::Scissors) => 0,
 
-                (RPS::Scissors, RPS::Rock) => 0,
-                (RPS::Scissors, RPS::Paper) => 1,
-                (RPS::Scissors, RPS::Scissors) => 0,
+                (Rps::Scissors, Rps::Rock) => 0,
+                (Rps::Scissors, Rps::Paper) => 1,
+                (Rps::Scissors, Rps::Scissors) => 0,
             }
         }
     }
 
     #[test]
     fn rock_beats_scissors() {
-        assert_eq!(1, RPS::Rock.points_against(&RPS::Scissors));
-        assert_eq!(0, RPS::Scissors.points_against(&RPS::Rock));
+        assert_eq!(1, Rps::Rock.points_against(&Rps::Scissors));
+        assert_eq!(0, Rps::Scissors.points_against(&Rps::Rock));
     }
 
     #[test]
     fn points_of_rock_rock_paper_scissors_are_1_1_2_1() {
         assert_eq!(
             vec![1, 1, 2, 1],
-            RPS::points_of(&[RPS::Rock, RPS::Rock, RPS::Paper, RPS::Scissors])
+            Rps::points_of(&[Rps::Rock, Rps::Rock, Rps::Paper, Rps::Scissors])
         );
     }
-
 }
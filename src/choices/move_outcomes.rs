@@ -0,0 +1,113 @@
+use super::{Action, Move, PointsAgainst};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MoveOutcomes {
+    overrides: Vec<(Move, Move, u8)>,
+}
+
+impl MoveOutcomes {
+    pub fn set(&mut self, m: Move, against: Move, points: u8) {
+        self.overrides.retain(|&(a, b, _)| (a, b) != (m, against));
+        if points != m.points_against(&against) {
+            self.overrides.push((m, against, points));
+            self.overrides
+                .sort_by_key(|&(a, b, _)| (a.to_index(), b.to_index()));
+        }
+    }
+
+    pub fn points_against(&self, m: Move, against: Move) -> u8 {
+        self.overrides
+            .iter()
+            .find(|&&(a, b, _)| (a, b) == (m, against))
+            .map_or_else(|| m.points_against(&against), |&(_, _, points)| points)
+    }
+
+    pub fn is_standard(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    pub(crate) fn action_points_against(&self, action: Action, against: Action) -> u8 {
+        match (Option::<Move>::from(action), Option::<Move>::from(against)) {
+            (Some(own), Some(other)) => self.points_against(own, other),
+            _ => action.points_against(&against),
+        }
+    }
+
+    pub(crate) fn points_of(&self, actions: &[Action]) -> Vec<u8> {
+        if self.is_standard() {
+            return Action::points_of(actions);
+        }
+
+        actions
+            .iter()
+            .map(|&a| {
+                actions
+                    .iter()
+                    .map(|&b| self.action_points_against(a, b))
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_standard_table() {
+        let outcomes = MoveOutcomes::default();
+        assert!(outcomes.is_standard());
+        for a in Move::all() {
+            for b in Move::all() {
+                assert_eq!(a.points_against(&b), outcomes.points_against(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn set_changes_only_that_pairing() {
+        let mut outcomes = MoveOutcomes::default();
+        outcomes.set(Move::Kick, Move::Smash, 1);
+        assert_eq!(1, outcomes.points_against(Move::Kick, Move::Smash));
+        assert_eq!(1, outcomes.points_against(Move::Smash, Move::Kick));
+        assert_eq!(
+            Move::Kick.points_against(&Move::Zap),
+            outcomes.points_against(Move::Kick, Move::Zap)
+        );
+    }
+
+    #[test]
+    fn setting_standard_value_restores_default() {
+        let mut outcomes = MoveOutcomes::default();
+        outcomes.set(Move::Kick, Move::Smash, 1);
+        outcomes.set(Move::Kick, Move::Smash, 0);
+        assert_eq!(MoveOutcomes::default(), outcomes);
+    }
+
+    #[test]
+    fn equality_does_not_depend_on_set_order() {
+        let mut first = MoveOutcomes::default();
+        first.set(Move::Kick, Move::Smash, 1);
+        first.set(Move::Zap, Move::Nose, 1);
+        let mut second = MoveOutcomes::default();
+        second.set(Move::Zap, Move::Nose, 1);
+        second.set(Move::Kick, Move::Smash, 1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn points_of_uses_overrides_for_moves_and_mirrors() {
+        let mut outcomes = MoveOutcomes::default();
+        outcomes.set(Move::Kick, Move::Smash, 1);
+        outcomes.set(Move::Smash, Move::Kick, 0);
+        assert_eq!(
+            vec![1, 0],
+            outcomes.points_of(&[Action::Mirror(Move::Kick), Action::Move(Move::Smash)])
+        );
+        assert_eq!(
+            vec![1, 0],
+            outcomes.points_of(&[Action::Move(Move::Kick), Action::Concede])
+        );
+    }
+}
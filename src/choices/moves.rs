@@ -1,6 +1,7 @@
 use super::PointsAgainst;
 use crate::helpers;
 
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
@@ -39,37 +40,19 @@ pub enum Move {
 
 impl Move {
     pub fn all() -> Vec<Move> {
-        vec![
-            Move::Kick,
-            Move::NinjaSword,
-            Move::Nunchucks,
-            Move::ShadowFireball,
-            Move::ShadowSlip,
-            Move::RunInCircles,
-            Move::LightningFastKarateChop,
-            Move::Rampage,
-            Move::Muscle,
-            Move::Zap,
-            Move::Regenerate,
-            Move::Gravedigger,
-            Move::ZombieCorps,
-            Move::Apocalypse,
-            Move::SamuraiSword,
-            Move::Helmet,
-            Move::Smash,
-            Move::StrongSmash,
-            Move::Lightning,
-            Move::Earthquake,
-            Move::Twist,
-            Move::Bend,
-            Move::JugglingKnives,
-            Move::AcidSpray,
-            Move::Nose,
-            Move::BackwardsMoustachio,
-            Move::NoseOfTheTaunted,
-            Move::MustacheMash,
-            Move::BigHairyDeal,
-        ]
+        MOVES.to_vec()
+    }
+
+    pub fn iter() -> impl Iterator<Item = Move> + Clone {
+        MOVES.iter().copied()
+    }
+
+    pub fn to_index(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_index(index: u8) -> Option<Move> {
+        MOVES.get(usize::from(index)).copied()
     }
 
     pub fn is_destructive(self) -> bool {
@@ -165,6 +148,46 @@ impl FromStr for Move {
     }
 }
 
+impl TryFrom<u8> for Move {
+    type Error = ();
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Move::from_index(index).ok_or(())
+    }
+}
+
+const MOVES: [Move; 29] = [
+    Move::Kick,
+    Move::NinjaSword,
+    Move::Nunchucks,
+    Move::ShadowFireball,
+    Move::ShadowSlip,
+    Move::RunInCircles,
+    Move::LightningFastKarateChop,
+    Move::Rampage,
+    Move::Muscle,
+    Move::Zap,
+    Move::Regenerate,
+    Move::Gravedigger,
+    Move::ZombieCorps,
+    Move::Apocalypse,
+    Move::SamuraiSword,
+    Move::Helmet,
+    Move::Smash,
+    Move::StrongSmash,
+    Move::Lightning,
+    Move::Earthquake,
+    Move::Twist,
+    Move::Bend,
+    Move::JugglingKnives,
+    Move::AcidSpray,
+    Move::Nose,
+    Move::BackwardsMoustachio,
+    Move::NoseOfTheTaunted,
+    Move::MustacheMash,
+    Move::BigHairyDeal,
+];
+
 const MOVE_OUTCOMES: [u8; 29 * 29] = [
     0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0,
     0, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -257,4 +280,13 @@ mod tests {
     fn nose_is_not_destructive() {
         assert!(!Move::Nose.is_destructive());
     }
+
+    #[test]
+    fn index_round_trips() {
+        for (i, m) in Move::all().into_iter().enumerate() {
+            assert_eq!(i as u8, m.to_index());
+            assert_eq!(Ok(m), Move::try_from(i as u8));
+        }
+        assert_eq!(None, Move::from_index(Move::all().len() as u8));
+    }
 }
@@ -53,11 +53,17 @@ pub(crate) struct CharacterStreak {
     times: u8,
 }
 
-impl Into<transparent::CharacterStreak> for CharacterStreak {
-    fn into(self) -> transparent::CharacterStreak {
+impl CharacterStreak {
+    pub(crate) fn new(character: Character, times: u8) -> Self {
+        Self { character, times }
+    }
+}
+
+impl From<CharacterStreak> for transparent::CharacterStreak {
+    fn from(streak: CharacterStreak) -> transparent::CharacterStreak {
         transparent::CharacterStreak {
-            character: self.character,
-            times: self.times,
+            character: streak.character,
+            times: streak.times,
         }
     }
 }
@@ -1,4 +1,6 @@
 mod character_streak;
 pub(crate) use character_streak::{CharacterChoices, CharacterStreak};
+mod pool;
+pub use pool::{Pool, PoolIter};
 mod queue;
 pub use queue::Queue;
@@ -0,0 +1,157 @@
+use crate::choices::{ArsenalItem, ArsenalSet};
+
+use std::fmt::{self, Debug, Formatter};
+use std::iter::FromIterator;
+
+const ITEM_COUNT: usize = 30;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Pool {
+    counts: [u8; ITEM_COUNT],
+}
+
+impl Pool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(&self, item: ArsenalItem) -> u8 {
+        self.counts[usize::from(item.to_index())]
+    }
+
+    pub fn contains(&self, item: ArsenalItem) -> bool {
+        self.count(item) > 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.iter().map(|&count| usize::from(count)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.iter().all(|&count| count == 0)
+    }
+
+    pub fn items(&self) -> ArsenalSet {
+        self.iter().collect()
+    }
+
+    pub fn iter(&self) -> PoolIter {
+        PoolIter {
+            counts: self.counts,
+            index: 0,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<ArsenalItem> {
+        self.iter().collect()
+    }
+
+    pub(crate) fn insert(&mut self, item: ArsenalItem) {
+        self.counts[usize::from(item.to_index())] += 1;
+    }
+
+    pub(crate) fn remove(&mut self, item: ArsenalItem) -> bool {
+        let count = &mut self.counts[usize::from(item.to_index())];
+        if *count == 0 {
+            false
+        } else {
+            *count -= 1;
+            true
+        }
+    }
+}
+
+impl Debug for Pool {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<ArsenalItem> for Pool {
+    fn from_iter<I: IntoIterator<Item = ArsenalItem>>(iter: I) -> Self {
+        let mut pool = Pool::new();
+        for item in iter {
+            pool.insert(item);
+        }
+        pool
+    }
+}
+
+impl IntoIterator for &Pool {
+    type Item = ArsenalItem;
+    type IntoIter = PoolIter;
+
+    fn into_iter(self) -> PoolIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PoolIter {
+    counts: [u8; ITEM_COUNT],
+    index: usize,
+}
+
+impl Iterator for PoolIter {
+    type Item = ArsenalItem;
+
+    fn next(&mut self) -> Option<ArsenalItem> {
+        while self.index < ITEM_COUNT {
+            if self.counts[self.index] > 0 {
+                self.counts[self.index] -= 1;
+                return ArsenalItem::from_index(self.index as u8);
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.counts[self.index.min(ITEM_COUNT)..]
+            .iter()
+            .map(|&count| usize::from(count))
+            .sum();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for PoolIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Move;
+
+    #[test]
+    fn pools_with_same_items_in_any_order_are_equal() {
+        let kick = ArsenalItem::Move(Move::Kick);
+        let a: Pool = vec![kick, ArsenalItem::Mirror, kick].into_iter().collect();
+        let b: Pool = vec![ArsenalItem::Mirror, kick, kick].into_iter().collect();
+        assert_eq!(a, b);
+        assert_eq!(2, a.count(kick));
+        assert_eq!(3, a.len());
+    }
+
+    #[test]
+    fn iteration_repeats_items_in_index_order() {
+        let kick = ArsenalItem::Move(Move::Kick);
+        let zap = ArsenalItem::Move(Move::Zap);
+        let pool: Pool = vec![ArsenalItem::Mirror, zap, kick, zap]
+            .into_iter()
+            .collect();
+        assert_eq!(vec![kick, zap, zap, ArsenalItem::Mirror], pool.to_vec());
+        assert_eq!(4, pool.iter().len());
+        assert_eq!(3, pool.items().len());
+    }
+
+    #[test]
+    fn remove_takes_one_copy() {
+        let kick = ArsenalItem::Move(Move::Kick);
+        let mut pool: Pool = vec![kick, kick].into_iter().collect();
+        assert!(pool.remove(kick));
+        assert!(pool.contains(kick));
+        assert!(pool.remove(kick));
+        assert!(!pool.remove(kick));
+        assert!(pool.is_empty());
+    }
+}
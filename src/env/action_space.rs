@@ -0,0 +1,78 @@
+use crate::choices::{Action, ArsenalItem, Booster, Character, Choice, DequeueChoice};
+
+use std::convert::TryFrom;
+
+pub(super) const CHARACTER_COUNT: usize = 4;
+pub(super) const BOOSTER_COUNT: usize = 9;
+pub(super) const ARSENAL_ITEM_COUNT: usize = 30;
+const DEQUEUE_CHOICE_COUNT: usize = ARSENAL_ITEM_COUNT + 2;
+const ACTION_COUNT: usize = 2 * (ARSENAL_ITEM_COUNT - 1) + 1;
+
+const BOOSTER_OFFSET: usize = CHARACTER_COUNT;
+const DEQUEUE_CHOICE_OFFSET: usize = BOOSTER_OFFSET + BOOSTER_COUNT;
+const ACTION_OFFSET: usize = DEQUEUE_CHOICE_OFFSET + DEQUEUE_CHOICE_COUNT;
+
+pub const ACTION_SPACE_SIZE: usize = ACTION_OFFSET + ACTION_COUNT;
+
+pub fn choice_to_index(choice: Choice) -> usize {
+    match choice {
+        Choice::Character(character) => usize::from(character.to_index()),
+        Choice::Booster(booster) => BOOSTER_OFFSET + usize::from(booster.to_index()),
+        Choice::DequeueChoice(dequeue_choice) => {
+            DEQUEUE_CHOICE_OFFSET + usize::from(dequeue_choice.to_index())
+        }
+        Choice::Action(action) => ACTION_OFFSET + usize::from(action.to_index()),
+    }
+}
+
+pub fn index_to_choice(index: usize) -> Option<Choice> {
+    if index < BOOSTER_OFFSET {
+        Character::try_from(local_index(index, 0)?)
+            .ok()
+            .map(Choice::Character)
+    } else if index < DEQUEUE_CHOICE_OFFSET {
+        Booster::try_from(local_index(index, BOOSTER_OFFSET)?)
+            .ok()
+            .map(Choice::Booster)
+    } else if index < ACTION_OFFSET {
+        DequeueChoice::from_index(local_index(index, DEQUEUE_CHOICE_OFFSET)?)
+            .map(Choice::DequeueChoice)
+    } else {
+        Action::from_index(local_index(index, ACTION_OFFSET)?).map(Choice::Action)
+    }
+}
+
+pub(super) fn arsenal_item_index(item: ArsenalItem) -> usize {
+    usize::from(item.to_index())
+}
+
+fn local_index(index: usize, offset: usize) -> Option<u8> {
+    u8::try_from(index - offset).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_index_round_trips() {
+        for index in 0..ACTION_SPACE_SIZE {
+            let choice = index_to_choice(index).unwrap();
+            assert_eq!(index, choice_to_index(choice));
+        }
+    }
+
+    #[test]
+    fn index_past_end_is_none() {
+        assert_eq!(None, index_to_choice(ACTION_SPACE_SIZE));
+        assert_eq!(None, index_to_choice(usize::MAX));
+    }
+
+    #[test]
+    fn concede_is_last_index() {
+        assert_eq!(
+            ACTION_SPACE_SIZE - 1,
+            choice_to_index(Choice::Action(Action::Concede))
+        );
+    }
+}
@@ -0,0 +1,198 @@
+mod action_space;
+pub use action_space::{choice_to_index, index_to_choice, ACTION_SPACE_SIZE};
+mod observation;
+pub use observation::{observation_len, observe};
+
+use crate::choices::BatchChoice;
+use crate::game::{BatchChoiceGame, Config};
+use crate::random::Prng;
+
+pub fn terminal_rewards(game: &BatchChoiceGame) -> Vec<f64> {
+    let player_count = game.player_count();
+    match game.winner_index() {
+        Some(winner) => (0..player_count)
+            .map(|i| {
+                if i == winner {
+                    (player_count - 1) as f64
+                } else {
+                    -1.0
+                }
+            })
+            .collect(),
+        None => vec![0.0; player_count],
+    }
+}
+
+pub fn legal_action_mask(game: &BatchChoiceGame, player_index: usize) -> Vec<bool> {
+    let mut mask = vec![false; ACTION_SPACE_SIZE];
+    if let Some(choices) = game.choices().to_choices().get(player_index) {
+        for &choice in choices {
+            mask[choice_to_index(choice)] = true;
+        }
+    }
+    mask
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub observations: Vec<Vec<f32>>,
+    pub rewards: Vec<f64>,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Env {
+    game: BatchChoiceGame,
+    rng: Prng,
+}
+
+impl Env {
+    pub fn new(config: Config) -> Self {
+        Self {
+            game: BatchChoiceGame::new(config),
+            rng: Prng::new(0),
+        }
+    }
+
+    pub fn game(&self) -> &BatchChoiceGame {
+        &self.game
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.game.player_count()
+    }
+
+    pub fn observation_len(&self) -> usize {
+        observation_len(self.game.config())
+    }
+
+    pub fn reset(&mut self, seed: u64) -> Vec<Vec<f32>> {
+        self.game = BatchChoiceGame::new(self.game.config().clone());
+        self.rng = Prng::new(seed);
+        self.observations()
+    }
+
+    pub fn step(&mut self, joint_action: &[usize]) -> Result<Step, ()> {
+        let choices = joint_action
+            .iter()
+            .map(|&index| index_to_choice(index).ok_or(()))
+            .collect::<Result<Vec<_>, ()>>()?;
+        self.game.choose(BatchChoice::from_choices(choices)?)?;
+
+        let done = self.game.is_over();
+        Ok(Step {
+            observations: self.observations(),
+            rewards: terminal_rewards(&self.game),
+            done,
+        })
+    }
+
+    pub fn observation(&self, player_index: usize) -> Vec<f32> {
+        observe(&self.game, player_index)
+    }
+
+    pub fn observations(&self) -> Vec<Vec<f32>> {
+        (0..self.player_count())
+            .map(|i| self.observation(i))
+            .collect()
+    }
+
+    pub fn legal_action_mask(&self, player_index: usize) -> Vec<bool> {
+        legal_action_mask(&self.game, player_index)
+    }
+
+    pub fn sample_joint_action(&mut self) -> Option<Vec<usize>> {
+        let choices = self.game.choices().to_choices();
+        if choices.is_empty() {
+            return None;
+        }
+
+        Some(
+            choices
+                .iter()
+                .map(|c| choice_to_index(c[self.rng.below(c.len())]))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{Character, Choice};
+
+    #[test]
+    fn reset_returns_observation_per_player() {
+        let mut env = Env::new(Config::default());
+        let observations = env.reset(0);
+        assert_eq!(2, observations.len());
+        assert_eq!(env.observation_len(), observations[0].len());
+    }
+
+    #[test]
+    fn mask_marks_characters_in_character_phase() {
+        let env = Env::new(Config::default());
+        let mask = env.legal_action_mask(0);
+        assert_eq!(4, mask.iter().filter(|&&legal| legal).count());
+        for character in Character::all() {
+            assert!(mask[choice_to_index(Choice::Character(character))]);
+        }
+    }
+
+    #[test]
+    fn illegal_step_is_rejected() {
+        let mut env = Env::new(Config::default());
+        let concede = choice_to_index(Choice::Action(crate::choices::Action::Concede));
+        assert!(env.step(&[concede, concede]).is_err());
+        assert!(env.step(&[ACTION_SPACE_SIZE, 0]).is_err());
+    }
+
+    fn play_random_episode(env: &mut Env, seed: u64) -> Step {
+        env.reset(seed);
+        let mut last = None;
+        while let Some(joint_action) = env.sample_joint_action() {
+            for (i, &index) in joint_action.iter().enumerate() {
+                assert!(env.legal_action_mask(i)[index]);
+            }
+            last = Some(env.step(&joint_action).unwrap());
+        }
+        last.unwrap()
+    }
+
+    #[test]
+    fn random_episode_ends_with_zero_sum_rewards() {
+        let mut env = Env::new(Config::default());
+        let last = play_random_episode(&mut env, 7);
+        let winner = env.game().winner_index().unwrap();
+        let mut expected = vec![-1.0; 2];
+        expected[winner] = 1.0;
+
+        assert!(last.done);
+        assert_eq!(expected, last.rewards);
+    }
+
+    #[test]
+    fn three_player_winner_gets_what_losers_lose() {
+        let mut env = Env::new(Config {
+            player_count: 3,
+            ..Config::default()
+        });
+        let last = play_random_episode(&mut env, 7);
+        let winner = env.game().winner_index().unwrap();
+        let mut expected = vec![-1.0; 3];
+        expected[winner] = 2.0;
+
+        assert!(last.done);
+        assert_eq!(expected, last.rewards);
+        assert_eq!(0.0, last.rewards.iter().sum::<f64>());
+    }
+
+    #[test]
+    fn reset_restarts_game() {
+        let mut env = Env::new(Config::default());
+        let initial = env.reset(1);
+        let joint_action = env.sample_joint_action().unwrap();
+        env.step(&joint_action).unwrap();
+        assert_eq!(initial, env.reset(1));
+    }
+}
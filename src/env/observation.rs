@@ -0,0 +1,114 @@
+use super::action_space::{arsenal_item_index, ARSENAL_ITEM_COUNT, BOOSTER_COUNT, CHARACTER_COUNT};
+use crate::game::{BatchChoiceGame, Config, PhaseKind};
+
+const PHASE_COUNT: usize = 5;
+const PLAYER_LEN: usize =
+    1 + CHARACTER_COUNT + BOOSTER_COUNT + CHARACTER_COUNT + 1 + 4 * ARSENAL_ITEM_COUNT;
+
+pub fn observation_len(config: &Config) -> usize {
+    PHASE_COUNT + PLAYER_LEN * usize::from(config.player_count)
+}
+
+pub fn observe(game: &BatchChoiceGame, player_index: usize) -> Vec<f32> {
+    let mut observation = vec![0.0; observation_len(game.config())];
+    let phase = match game.phase_kind() {
+        PhaseKind::Character => 0,
+        PhaseKind::Booster => 1,
+        PhaseKind::Dequeue => 2,
+        PhaseKind::Action => 3,
+        PhaseKind::Final => 4,
+    };
+    observation[phase] = 1.0;
+
+    let player_count = game.player_count();
+    for offset in 0..player_count {
+        let i = (player_index + offset) % player_count;
+        let start = PHASE_COUNT + offset * PLAYER_LEN;
+        encode_player(game, i, &mut observation[start..start + PLAYER_LEN]);
+    }
+
+    observation
+}
+
+fn encode_player(game: &BatchChoiceGame, i: usize, block: &mut [f32]) {
+    let config = game.config();
+    let (points, rest) = block.split_at_mut(1);
+    let (character, rest) = rest.split_at_mut(CHARACTER_COUNT);
+    let (booster, rest) = rest.split_at_mut(BOOSTER_COUNT);
+    let (streak_character, rest) = rest.split_at_mut(CHARACTER_COUNT);
+    let (streak_times, rest) = rest.split_at_mut(1);
+    let (arsenal, rest) = rest.split_at_mut(ARSENAL_ITEM_COUNT);
+    let (entrance, rest) = rest.split_at_mut(ARSENAL_ITEM_COUNT);
+    let (pool, exit) = rest.split_at_mut(ARSENAL_ITEM_COUNT);
+
+    points[0] = f32::from(game.points(i).unwrap_or(0)) / f32::from(config.points_to_win.max(1));
+    if let Some(c) = game.character(i) {
+        character[usize::from(c.to_index())] = 1.0;
+    }
+    if let Some(b) = game.booster(i) {
+        booster[usize::from(b.to_index())] = 1.0;
+    }
+    if let Some(streak) = game.character_streak(i) {
+        streak_character[usize::from(streak.character.to_index())] = 1.0;
+        streak_times[0] =
+            f32::from(streak.times) / f32::from(config.max_character_repetitions.max(1));
+    }
+    for item in game.arsenal(i).unwrap_or_default() {
+        arsenal[arsenal_item_index(item)] = 1.0;
+    }
+    if let Some(queue) = game.queue(i) {
+        if let Some(item) = queue.entrance() {
+            entrance[arsenal_item_index(item)] = 1.0;
+        }
+        for item in queue.pool() {
+            pool[arsenal_item_index(item)] += 1.0;
+        }
+        if let Some(item) = queue.exit() {
+            exit[arsenal_item_index(item)] = 1.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{BatchChoice, Character};
+
+    #[test]
+    fn observation_has_fixed_length() {
+        let mut game = BatchChoiceGame::default();
+        let len = observation_len(game.config());
+        assert_eq!(len, observe(&game, 0).len());
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        assert_eq!(len, observe(&game, 1).len());
+    }
+
+    #[test]
+    fn observation_puts_own_player_first() {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        let ninja = observe(&game, 0);
+        let samurai = observe(&game, 1);
+
+        assert_eq!(
+            1.0,
+            ninja[PHASE_COUNT + 1 + usize::from(Character::Ninja.to_index())]
+        );
+        assert_eq!(
+            1.0,
+            samurai[PHASE_COUNT + 1 + usize::from(Character::Samurai.to_index())]
+        );
+        assert_eq!(
+            ninja[PHASE_COUNT..PHASE_COUNT + PLAYER_LEN],
+            samurai[PHASE_COUNT + PLAYER_LEN..]
+        );
+    }
+}
@@ -1,31 +1,79 @@
-use super::{Config, Phase};
+use super::zobrist::{self, PlayerFeatures};
+use super::{Config, Phase, PhaseKind};
 use crate::{
     choices::{
-        Action, BatchChoice, BatchChoices, Booster, CanChoose, Character, Choose, DequeueChoice,
-        PointsAgainst,
+        Action, ArsenalSet, BatchChoice, BatchChoices, Booster, CanChoose, Character, Choice,
+        Choices, Choose, DequeueChoice, ExplainIllegal, IllegalChoice, LegalActions,
+        LegalDequeueChoices, PointsAgainst,
     },
     helpers::HasDuplicates,
     outcomes::{ActionPointsDestroyed, CharacterHeadstart, Outcome},
     players::{CharacterlessPlayer, DequeueChoicelessPlayer, FinishedPlayer},
-    scoreboard::Scoreboard,
+    scoreboard::{CharacterStreak, QueueRef, Scoreboard},
 };
 
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::mem;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone)]
 pub struct BatchChoiceGame {
     config: Config,
     phase: Phase,
+    round_number: u16,
+    character_rechoose_count: u16,
+    rounds_without_progress: u8,
+    repetitions: HashMap<u64, u8>,
+    zobrist_key: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct FullState {
+    phase: Phase,
+    character_rechoose_count: u16,
+    rounds_without_progress: u8,
+    repetitions: Vec<(u64, u8)>,
 }
 
 impl BatchChoiceGame {
     pub fn new(config: Config) -> Self {
+        let phase = Phase::Character(Self::initial_players(&config));
+        let zobrist_key = zobrist::game_key(PhaseKind::Character, &PlayerFeatures::all(&phase));
+
         Self {
-            config: config.clone(),
-            phase: Phase::Character(Self::initial_players(&config)),
+            config,
+            phase,
+            round_number: 0,
+            character_rechoose_count: 0,
+            rounds_without_progress: 0,
+            repetitions: HashMap::new(),
+            zobrist_key,
         }
     }
 
+    pub(super) fn from_parts(
+        config: Config,
+        phase: Phase,
+        character_rechoose_count: u16,
+        rounds_without_progress: u8,
+    ) -> Self {
+        let zobrist_key = zobrist::game_key(phase.kind(), &PlayerFeatures::all(&phase));
+
+        Self {
+            config,
+            phase,
+            round_number: 0,
+            character_rechoose_count,
+            rounds_without_progress,
+            repetitions: HashMap::new(),
+            zobrist_key,
+        }
+    }
+
+    pub(super) fn phase(&self) -> &Phase {
+        &self.phase
+    }
+
     fn initial_players(config: &Config) -> Vec<CharacterlessPlayer> {
         let mut players: Vec<CharacterlessPlayer> = vec![];
         for _ in 0..config.player_count {
@@ -38,6 +86,47 @@ impl BatchChoiceGame {
         &self.config
     }
 
+    pub fn phase_kind(&self) -> PhaseKind {
+        self.phase.kind()
+    }
+
+    pub fn round_number(&self) -> u16 {
+        self.round_number
+    }
+
+    pub fn character_rechoose_count(&self) -> u16 {
+        self.character_rechoose_count
+    }
+
+    pub fn rounds_without_progress(&self) -> u8 {
+        self.rounds_without_progress
+    }
+
+    pub fn zobrist_key(&self) -> u64 {
+        self.zobrist_key
+    }
+
+    pub(crate) fn full_state(&self) -> FullState {
+        let mut repetitions: Vec<(u64, u8)> =
+            self.repetitions.iter().map(|(k, v)| (*k, *v)).collect();
+        repetitions.sort_unstable();
+
+        FullState {
+            phase: self.phase.clone(),
+            character_rechoose_count: self.character_rechoose_count,
+            rounds_without_progress: self.rounds_without_progress,
+            repetitions,
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.phase_kind() == PhaseKind::Final
+    }
+
+    pub fn is_draw(&self) -> bool {
+        self.is_over() && self.winner_index().is_none()
+    }
+
     pub fn choices(&self) -> BatchChoices {
         match &self.phase {
             Phase::Character(players) => {
@@ -56,7 +145,66 @@ impl BatchChoiceGame {
                 BatchChoices::Actions(players.iter().map(|p| p.choices()).collect())
             }
 
-            Phase::Final(_) => BatchChoices::None,
+            Phase::Final(_) | Phase::Drawn(_) => BatchChoices::None,
+        }
+    }
+
+    pub fn legal_actions(&self, player_index: usize) -> Option<LegalActions> {
+        match &self.phase {
+            Phase::Action(players) => players.get(player_index).map(|p| p.legal_actions()),
+            _ => None,
+        }
+    }
+
+    pub fn legal_dequeue_choices(&self, player_index: usize) -> Option<LegalDequeueChoices> {
+        match &self.phase {
+            Phase::Dequeue(players) => players.get(player_index).map(|p| p.legal_dequeue_choices()),
+            _ => None,
+        }
+    }
+
+    pub fn choices_for(&self, player_index: usize) -> Option<Choices> {
+        let choices = match &self.phase {
+            Phase::Character(players) => Choices::Characters(players.get(player_index)?.choices()),
+            Phase::Booster(players) => Choices::Boosters(players.get(player_index)?.choices()),
+            Phase::Dequeue(players) => {
+                Choices::DequeueChoices(players.get(player_index)?.choices())
+            }
+            Phase::Action(players) => Choices::Actions(players.get(player_index)?.choices()),
+            Phase::Final(players) => {
+                players.get(player_index)?;
+                Choices::None
+            }
+            Phase::Drawn(phase) if player_index < phase.player_count() => Choices::None,
+            Phase::Drawn(_) => return None,
+        };
+
+        Some(choices)
+    }
+
+    pub fn can_choose(&self, player_index: usize, choice: &Choice) -> bool {
+        self.explain_illegal(player_index, choice).is_none()
+    }
+
+    pub fn explain_illegal(&self, player_index: usize, choice: &Choice) -> Option<IllegalChoice> {
+        if player_index >= self.phase.player_count() {
+            return Some(IllegalChoice::NoSuchPlayer(player_index));
+        }
+
+        match (&self.phase, choice) {
+            (Phase::Character(players), Choice::Character(character)) => {
+                players[player_index].explain_illegal(character)
+            }
+            (Phase::Booster(players), Choice::Booster(booster)) => {
+                players[player_index].explain_illegal(booster)
+            }
+            (Phase::Dequeue(players), Choice::DequeueChoice(dequeue_choice)) => {
+                players[player_index].explain_illegal(dequeue_choice)
+            }
+            (Phase::Action(players), Choice::Action(action)) => {
+                players[player_index].explain_illegal(action)
+            }
+            (phase, _) => Some(IllegalChoice::WrongPhase(phase.kind())),
         }
     }
 
@@ -76,15 +224,56 @@ impl BatchChoiceGame {
         }
     }
 
+    fn set_phase(&mut self, phase: Phase) {
+        let (old_kind, new_kind) = (self.phase.kind(), phase.kind());
+        if old_kind != new_kind {
+            self.zobrist_key ^= zobrist::phase_key(old_kind) ^ zobrist::phase_key(new_kind);
+        }
+        self.phase = phase;
+    }
+
+    fn advance_players<P, Q>(
+        &mut self,
+        players: Vec<P>,
+        mut advance: impl FnMut(usize, P) -> Q,
+    ) -> Vec<Q>
+    where
+        for<'a> PlayerFeatures: From<&'a P> + From<&'a Q>,
+    {
+        let mut key = self.zobrist_key;
+        let players = players
+            .into_iter()
+            .enumerate()
+            .map(|(i, player)| {
+                let before = PlayerFeatures::from(&player);
+                let player = advance(i, player);
+                key ^= zobrist::player_delta(i, &before, &PlayerFeatures::from(&player));
+                player
+            })
+            .collect();
+        self.zobrist_key = key;
+        players
+    }
+
     fn choose_characters(&mut self, characters: Vec<Character>) -> Result<Outcome, ()> {
         if let Phase::Character(players) = &mut self.phase {
             if !players.can_choose(&characters) {
                 Err(())
             } else if characters.has_duplicates() {
-                for (player, character) in players.iter_mut().zip(&characters) {
+                for (i, (player, character)) in players.iter_mut().zip(&characters).enumerate() {
+                    let before = PlayerFeatures::from(&*player);
                     player.add_to_streak(*character);
+                    self.zobrist_key ^=
+                        zobrist::player_delta(i, &before, &PlayerFeatures::from(&*player));
+                }
+                self.character_rechoose_count = self.character_rechoose_count.saturating_add(1);
+
+                if self.record_round(false) {
+                    self.finish_stalemate();
+                    Ok(Outcome::Draw(vec![]))
+                } else {
+                    Ok(Outcome::CharacterPhaseRechoose(characters))
                 }
-                Ok(Outcome::CharacterPhaseRechoose(characters))
             } else {
                 let dummy = vec![];
                 let players = mem::replace(players, dummy);
@@ -108,13 +297,12 @@ impl BatchChoiceGame {
             .map(|(character, headstart)| CharacterHeadstart(*character, headstart))
             .collect();
 
-        self.phase = Phase::Booster(
-            players
-                .into_iter()
-                .zip(&character_headstarts)
-                .map(|(p, ch)| p.into_boosterless(ch.clone()))
-                .collect(),
-        );
+        let players = self.advance_players(players, |i, p| {
+            p.into_boosterless(character_headstarts[i].clone())
+        });
+        self.set_phase(Phase::Booster(players));
+
+        self.rounds_without_progress = 0;
 
         Outcome::CharacterPhaseDone(character_headstarts)
     }
@@ -126,13 +314,9 @@ impl BatchChoiceGame {
             } else {
                 let dummy = vec![];
                 let players = mem::replace(players, dummy);
-                self.phase = Phase::Dequeue(
-                    players
-                        .into_iter()
-                        .zip(&boosters)
-                        .map(|(player, booster)| player.into_dequeue_choiceless(*booster))
-                        .collect(),
-                );
+                let players =
+                    self.advance_players(players, |i, p| p.into_dequeue_choiceless(boosters[i]));
+                self.set_phase(Phase::Dequeue(players));
                 Ok(Outcome::BoosterPhaseDone(boosters))
             }
         } else {
@@ -150,13 +334,9 @@ impl BatchChoiceGame {
             } else {
                 let dummy = vec![];
                 let players = mem::replace(players, dummy);
-                self.phase = Phase::Action(
-                    players
-                        .into_iter()
-                        .zip(&dequeue_choices)
-                        .map(|(player, dequeue_choice)| player.into_actionless(*dequeue_choice))
-                        .collect(),
-                );
+                let players =
+                    self.advance_players(players, |i, p| p.into_actionless(dequeue_choices[i]));
+                self.set_phase(Phase::Action(players));
                 Ok(Outcome::DequeuePhaseDone(dequeue_choices))
             }
         } else {
@@ -169,7 +349,7 @@ impl BatchChoiceGame {
             if !players.can_choose(&actions) {
                 Err(())
             } else {
-                let points_gained = Action::points_of(&actions);
+                let points_gained = self.config.move_outcomes.points_of(&actions);
                 let mut action_points_destroyed: Vec<ActionPointsDestroyed> = actions
                     .iter()
                     .zip(points_gained)
@@ -189,6 +369,12 @@ impl BatchChoiceGame {
                     apd.1 -= points as i8;
                 }
 
+                self.round_number = self.round_number.saturating_add(1);
+                let progressed = action_points_destroyed
+                    .iter()
+                    .zip(players.iter())
+                    .any(|(apd, player)| apd.1 > 0 || (apd.1 < 0 && player.points() > 0));
+
                 let points_to_win = self.config.points_to_win;
                 let mut new_points = players
                     .iter()
@@ -198,25 +384,28 @@ impl BatchChoiceGame {
                 if have_any_won {
                     let dummy = vec![];
                     let players = mem::replace(players, dummy);
-                    let finished_players: Vec<FinishedPlayer> = players
-                        .into_iter()
-                        .zip(&action_points_destroyed)
-                        .map(|(player, apd)| player.into_finished(apd.clone()))
-                        .collect();
-                    self.phase = Phase::Final(finished_players.clone());
+                    let finished_players: Vec<FinishedPlayer> = self
+                        .advance_players(players, |i, p| {
+                            p.into_finished(action_points_destroyed[i].clone())
+                        });
+                    self.set_phase(Phase::Final(finished_players));
 
                     Ok(Outcome::GameOver(action_points_destroyed))
                 } else {
                     let dummy = vec![];
                     let players = mem::replace(players, dummy);
-                    let dequeueing_players: Vec<DequeueChoicelessPlayer> = players
-                        .into_iter()
-                        .zip(&action_points_destroyed)
-                        .map(|(p, apd)| p.into_dequeue_choiceless(apd.clone()))
-                        .collect();
-                    self.phase = Phase::Dequeue(dequeueing_players);
-
-                    Ok(Outcome::ActionPhaseDone(action_points_destroyed))
+                    let dequeueing_players: Vec<DequeueChoicelessPlayer> = self
+                        .advance_players(players, |i, p| {
+                            p.into_dequeue_choiceless(action_points_destroyed[i].clone())
+                        });
+                    self.set_phase(Phase::Dequeue(dequeueing_players));
+
+                    if self.record_round(progressed) {
+                        self.finish_stalemate();
+                        Ok(Outcome::Draw(action_points_destroyed))
+                    } else {
+                        Ok(Outcome::ActionPhaseDone(action_points_destroyed))
+                    }
                 }
             }
         } else {
@@ -224,6 +413,31 @@ impl BatchChoiceGame {
         }
     }
 
+    fn record_round(&mut self, progressed: bool) -> bool {
+        self.rounds_without_progress = if progressed {
+            0
+        } else {
+            self.rounds_without_progress.saturating_add(1)
+        };
+
+        let repetitions = if self.config.max_state_repetitions.is_some() {
+            let count = self.repetitions.entry(self.zobrist_key).or_insert(0);
+            *count = count.saturating_add(1);
+            *count
+        } else {
+            0
+        };
+
+        self.config
+            .is_stalemate(repetitions, self.rounds_without_progress)
+    }
+
+    fn finish_stalemate(&mut self) {
+        let phase = mem::replace(&mut self.phase, Phase::Final(vec![]));
+        self.zobrist_key ^= zobrist::phase_key(phase.kind()) ^ zobrist::phase_key(PhaseKind::Final);
+        self.phase = Phase::Drawn(Box::new(phase));
+    }
+
     pub fn winner_index(&self) -> Option<usize> {
         match &self.phase {
             Phase::Final(players) => players
@@ -236,6 +450,49 @@ impl BatchChoiceGame {
     pub fn scoreboard(&self) -> Scoreboard {
         self.phase.clone().into()
     }
+
+    pub fn player_count(&self) -> usize {
+        self.phase.player_count()
+    }
+
+    pub fn points(&self, player_index: usize) -> Option<u8> {
+        self.phase.points(player_index)
+    }
+
+    pub fn character(&self, player_index: usize) -> Option<Character> {
+        self.phase.character(player_index)
+    }
+
+    pub fn booster(&self, player_index: usize) -> Option<Booster> {
+        self.phase.booster(player_index)
+    }
+
+    pub fn arsenal(&self, player_index: usize) -> Option<ArsenalSet> {
+        self.phase.arsenal(player_index)
+    }
+
+    pub fn queue(&self, player_index: usize) -> Option<QueueRef<'_>> {
+        self.phase.queue(player_index)
+    }
+
+    pub fn character_streak(&self, player_index: usize) -> Option<CharacterStreak> {
+        self.phase.character_streak(player_index)
+    }
+}
+
+impl PartialEq for BatchChoiceGame {
+    fn eq(&self, other: &BatchChoiceGame) -> bool {
+        self.config == other.config && self.phase == other.phase
+    }
+}
+
+impl Eq for BatchChoiceGame {}
+
+impl Hash for BatchChoiceGame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.config.hash(state);
+        self.phase.hash(state);
+    }
 }
 
 impl Default for BatchChoiceGame {
@@ -457,6 +714,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn action_points_follow_config_move_outcomes() {
+        use crate::choices::{ArsenalItem, Move, MoveOutcomes};
+
+        let mut move_outcomes = MoveOutcomes::default();
+        move_outcomes.set(Move::ShadowFireball, Move::Lightning, 0);
+        move_outcomes.set(Move::Lightning, Move::ShadowFireball, 1);
+        let mut game = BatchChoiceGame::new(Config {
+            move_outcomes,
+            ..Config::default()
+        });
+        let ninja_samurai = BatchChoice::Characters(vec![Character::Ninja, Character::Samurai]);
+        let shadow_atlas = BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]);
+        let mirror_mirror = BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+        ]);
+        let fireball_lightning = BatchChoice::Actions(vec![
+            Action::Move(Move::ShadowFireball),
+            Action::Move(Move::Lightning),
+        ]);
+
+        game.choose(ninja_samurai).unwrap();
+        game.choose(shadow_atlas).unwrap();
+        game.choose(mirror_mirror).unwrap();
+        assert_eq!(
+            Ok(Outcome::ActionPhaseDone(vec![
+                ActionPointsDestroyed(Action::Move(Move::ShadowFireball), 0, false),
+                ActionPointsDestroyed(Action::Move(Move::Lightning), 1, false),
+            ])),
+            game.choose(fireball_lightning)
+        );
+    }
+
     #[test]
     fn zap_destroys_shadow_fireball() {
         use crate::choices::{ArsenalItem, Move};
@@ -689,4 +980,443 @@ mod tests {
         println!("{:#?}", game);
         assert_eq!(Some(1), game.winner_index());
     }
+
+    #[test]
+    fn player_accessors_match_scoreboard() {
+        use crate::choices::{ArsenalItem, Move};
+
+        let mut game = BatchChoiceGame::default();
+        assert_eq!(2, game.player_count());
+        assert_eq!(None, game.points(0));
+        assert_eq!(None, game.character_streak(0));
+
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Ninja,
+        ]))
+        .unwrap();
+        assert_eq!(
+            Some(CharacterStreak {
+                character: Character::Ninja,
+                times: 1,
+            }),
+            game.character_streak(1)
+        );
+
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+
+        let players = game.scoreboard().actionless().unwrap();
+        for (i, player) in players.iter().enumerate() {
+            assert_eq!(Some(player.points), game.points(i));
+            assert_eq!(Some(player.character), game.character(i));
+            assert_eq!(Some(player.booster), game.booster(i));
+            assert_eq!(Some(ArsenalSet::from(&player.arsenal[..])), game.arsenal(i));
+            let queue: crate::scoreboard::Queue = game.queue(i).unwrap().into();
+            assert_eq!(player.queue, queue);
+        }
+        assert_eq!(Some(ArsenalItem::Mirror), game.queue(0).unwrap().exit());
+        assert!(game
+            .arsenal(1)
+            .unwrap()
+            .contains(ArsenalItem::Move(Move::Lightning)));
+        assert_eq!(None, game.points(2));
+    }
+
+    #[test]
+    fn choices_for_matches_batch_choices() {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+
+        let boosters = game.choices().boosters().unwrap();
+        for (i, player_boosters) in boosters.into_iter().enumerate() {
+            assert_eq!(
+                Some(Choices::Boosters(player_boosters)),
+                game.choices_for(i)
+            );
+        }
+        assert_eq!(None, game.choices_for(2));
+    }
+
+    fn full_zobrist_key(game: &BatchChoiceGame) -> u64 {
+        zobrist::game_key(game.phase_kind(), &PlayerFeatures::all(&game.phase))
+    }
+
+    #[test]
+    fn zobrist_key_is_maintained_incrementally() {
+        use crate::random::Prng;
+
+        for seed in 0..20 {
+            let mut rng = Prng::new(seed);
+            let mut game = BatchChoiceGame::default();
+            while !game.is_over() {
+                let choices = game
+                    .choices()
+                    .to_choices()
+                    .iter()
+                    .map(|c| c[rng.below(c.len())])
+                    .collect();
+                game.choose(BatchChoice::from_choices(choices).unwrap())
+                    .unwrap();
+                assert_eq!(full_zobrist_key(&game), game.zobrist_key());
+            }
+        }
+    }
+
+    #[test]
+    fn zobrist_key_distinguishes_states() {
+        let mut a = BatchChoiceGame::default();
+        let mut b = BatchChoiceGame::default();
+        assert_eq!(a.zobrist_key(), b.zobrist_key());
+
+        a.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        b.choose(BatchChoice::Characters(vec![
+            Character::Samurai,
+            Character::Ninja,
+        ]))
+        .unwrap();
+        assert_ne!(a.zobrist_key(), b.zobrist_key());
+
+        let mut c = BatchChoiceGame::default();
+        c.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Ninja,
+        ]))
+        .unwrap();
+        assert_ne!(BatchChoiceGame::default().zobrist_key(), c.zobrist_key());
+    }
+
+    #[test]
+    fn zobrist_key_is_stable() {
+        let mut game = BatchChoiceGame::default();
+        assert_eq!(0xa957_8c7c_5514_4928, game.zobrist_key());
+
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        assert_eq!(0xfe99_a3cd_507f_a9ef, game.zobrist_key());
+    }
+
+    #[test]
+    fn legal_choice_iterators_match_choices_for() {
+        use crate::choices::ArsenalItem;
+
+        let mut game = BatchChoiceGame::default();
+        assert!(game.legal_dequeue_choices(0).is_none());
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+
+        for i in 0..2 {
+            assert_eq!(
+                Some(Choices::DequeueChoices(
+                    game.legal_dequeue_choices(i).unwrap().collect()
+                )),
+                game.choices_for(i)
+            );
+        }
+        assert!(game.legal_actions(0).is_none());
+
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        for i in 0..2 {
+            assert_eq!(
+                Some(Choices::Actions(game.legal_actions(i).unwrap().collect())),
+                game.choices_for(i)
+            );
+        }
+        assert!(game.legal_actions(2).is_none());
+    }
+
+    #[test]
+    fn can_choose_agrees_with_choices_for() {
+        let mut game = BatchChoiceGame::default();
+        for _ in 0..game.config.max_character_repetitions {
+            game.choose(BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Ninja,
+            ]))
+            .unwrap();
+        }
+
+        let choices = game.choices_for(0).unwrap();
+        for character in Character::all() {
+            let choice = Choice::Character(character);
+            assert_eq!(choices.contains(&choice), game.can_choose(0, &choice));
+        }
+    }
+
+    #[test]
+    fn explain_illegal_explains_illegal_choices() {
+        let mut game = BatchChoiceGame::default();
+        for _ in 0..game.config.max_character_repetitions {
+            game.choose(BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Ninja,
+            ]))
+            .unwrap();
+        }
+
+        assert_eq!(
+            Some(IllegalChoice::CharacterRepeated(Character::Ninja, 3)),
+            game.explain_illegal(0, &Choice::Character(Character::Ninja))
+        );
+        assert_eq!(
+            Some(IllegalChoice::WrongPhase(PhaseKind::Character)),
+            game.explain_illegal(0, &Choice::Booster(Booster::Shadow))
+        );
+        assert_eq!(
+            Some(IllegalChoice::NoSuchPlayer(2)),
+            game.explain_illegal(2, &Choice::Character(Character::Zombie))
+        );
+        assert_eq!(
+            None,
+            game.explain_illegal(1, &Choice::Character(Character::Zombie))
+        );
+    }
+
+    #[test]
+    fn phase_kind_follows_phases() {
+        use crate::choices::{ArsenalItem, Move};
+
+        let mut game = BatchChoiceGame::new(Config {
+            points_to_win: 1,
+            ..Config::default()
+        });
+        assert_eq!(PhaseKind::Character, game.phase_kind());
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Zombie,
+        ]))
+        .unwrap();
+        assert_eq!(PhaseKind::Booster, game.phase_kind());
+        game.choose(BatchChoice::Boosters(vec![
+            Booster::Shadow,
+            Booster::Regenerative,
+        ]))
+        .unwrap();
+        assert_eq!(PhaseKind::Dequeue, game.phase_kind());
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+        ]))
+        .unwrap();
+        assert_eq!(PhaseKind::Action, game.phase_kind());
+        assert!(!game.is_over());
+        game.choose(BatchChoice::Actions(vec![
+            Action::Move(Move::ShadowSlip),
+            Action::Move(Move::Regenerate),
+        ]))
+        .unwrap();
+        assert_eq!(PhaseKind::Final, game.phase_kind());
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn round_number_counts_completed_action_phases() {
+        use crate::choices::{ArsenalItem, Move};
+
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+        ]))
+        .unwrap();
+        assert_eq!(0, game.round_number());
+        game.choose(BatchChoice::Actions(vec![
+            Action::Move(Move::ShadowFireball),
+            Action::Move(Move::Lightning),
+        ]))
+        .unwrap();
+        assert_eq!(1, game.round_number());
+    }
+
+    #[test]
+    fn character_rechoose_count_counts_duplicate_picks() {
+        let mut game = BatchChoiceGame::default();
+        let ninja_ninja = BatchChoice::Characters(vec![Character::Ninja, Character::Ninja]);
+        game.choose(ninja_ninja.clone()).unwrap();
+        game.choose(ninja_ninja).unwrap();
+        assert_eq!(2, game.character_rechoose_count());
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Zombie,
+        ]))
+        .unwrap();
+        assert_eq!(2, game.character_rechoose_count());
+    }
+
+    #[test]
+    fn full_state_distinguishes_counters_that_equality_ignores() {
+        let config = Config {
+            max_state_repetitions: Some(3),
+            ..Config::default()
+        };
+        let ninja_ninja = BatchChoice::Characters(vec![Character::Ninja, Character::Ninja]);
+        let zombie_zombie = BatchChoice::Characters(vec![Character::Zombie, Character::Zombie]);
+        let mut direct = BatchChoiceGame::new(config.clone());
+        direct.choose(ninja_ninja.clone()).unwrap();
+        direct.choose(ninja_ninja.clone()).unwrap();
+        let mut detour = BatchChoiceGame::new(config);
+        detour.choose(zombie_zombie).unwrap();
+        detour.choose(ninja_ninja.clone()).unwrap();
+        detour.choose(ninja_ninja).unwrap();
+
+        assert_eq!(direct, detour);
+        assert_ne!(direct.full_state(), detour.full_state());
+        assert_eq!(direct.full_state(), direct.clone().full_state());
+    }
+
+    #[test]
+    fn game_is_drawn_if_character_picks_repeat_a_position() {
+        let mut game = BatchChoiceGame::new(Config {
+            max_state_repetitions: Some(2),
+            ..Config::default()
+        });
+        let ninja_ninja = BatchChoice::Characters(vec![Character::Ninja, Character::Ninja]);
+        let zombie_zombie = BatchChoice::Characters(vec![Character::Zombie, Character::Zombie]);
+
+        for _ in 0..game.config.max_character_repetitions {
+            game.choose(ninja_ninja.clone()).unwrap();
+        }
+        game.choose(zombie_zombie).unwrap();
+        assert!(!game.is_over());
+
+        assert_eq!(Ok(Outcome::Draw(vec![])), game.choose(ninja_ninja.clone()));
+        assert!(game.is_over());
+        assert!(game.is_draw());
+        assert_eq!(PhaseKind::Final, game.phase_kind());
+        assert_eq!(PhaseKind::Final, game.scoreboard().phase_kind());
+        assert!(game.scoreboard().drawn().unwrap().characterless().is_some());
+        assert_eq!(BatchChoices::None, game.choices());
+        assert_eq!(Some(Choices::None), game.choices_for(0));
+        assert!(game.choose(ninja_ninja).is_err());
+    }
+
+    #[test]
+    fn game_is_drawn_if_no_points_change_for_too_many_rounds() {
+        use crate::choices::{ArsenalItem, Move};
+
+        let mut game = BatchChoiceGame::new(Config {
+            max_rounds_without_progress: Some(1),
+            ..Config::default()
+        });
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Clown,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![
+            Booster::Shadow,
+            Booster::Moustachio,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::Decline,
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        let outcome = game
+            .choose(BatchChoice::Actions(vec![
+                Action::Move(Move::Kick),
+                Action::Move(Move::MustacheMash),
+            ]))
+            .unwrap();
+
+        assert!(outcome.draw().is_some());
+        assert!(game.is_draw());
+        assert_eq!(None, game.winner_index());
+        assert_eq!(PhaseKind::Final, game.scoreboard().phase_kind());
+        assert!(game.scoreboard().drawn().unwrap().dequeueing().is_some());
+        assert_eq!(
+            Some(ArsenalItem::Move(Move::Kick)),
+            game.queue(0).unwrap().entrance()
+        );
+    }
+
+    #[test]
+    fn same_position_reached_by_different_paths_is_equal() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |game: &BatchChoiceGame| {
+            let mut hasher = DefaultHasher::new();
+            game.hash(&mut hasher);
+            hasher.finish()
+        };
+        let zombie_zombie = BatchChoice::Characters(vec![Character::Zombie, Character::Zombie]);
+
+        let mut direct = BatchChoiceGame::default();
+        direct.choose(zombie_zombie.clone()).unwrap();
+        let mut detour = BatchChoiceGame::default();
+        detour
+            .choose(BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Ninja,
+            ]))
+            .unwrap();
+        detour.choose(zombie_zombie).unwrap();
+
+        assert_ne!(
+            direct.character_rechoose_count(),
+            detour.character_rechoose_count()
+        );
+        assert_eq!(direct, detour);
+        assert_eq!(hash(&direct), hash(&detour));
+        assert_eq!(direct.zobrist_key(), detour.zobrist_key());
+    }
+
+    #[test]
+    fn game_is_not_drawn_without_limits() {
+        let mut game = BatchChoiceGame::new(Config {
+            max_state_repetitions: None,
+            max_rounds_without_progress: None,
+            ..Config::default()
+        });
+        let ninja_ninja = BatchChoice::Characters(vec![Character::Ninja, Character::Ninja]);
+        let zombie_zombie = BatchChoice::Characters(vec![Character::Zombie, Character::Zombie]);
+
+        for _ in 0..10 {
+            for _ in 0..game.config.max_character_repetitions {
+                game.choose(ninja_ninja.clone()).unwrap();
+            }
+            game.choose(zombie_zombie.clone()).unwrap();
+        }
+        assert!(!game.is_over());
+    }
 }
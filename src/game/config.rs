@@ -1,13 +1,29 @@
+use crate::choices::MoveOutcomes;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Config {
     pub player_count: u8,
     pub points_to_win: u8,
     pub max_character_repetitions: u8,
     pub max_arsenal_items: u8,
+    pub max_state_repetitions: Option<u8>,
+    pub max_rounds_without_progress: Option<u8>,
+    pub move_outcomes: MoveOutcomes,
 }
 
 impl Config {
-    pub(super) fn deductions(&self, points: Vec<u8>) -> Vec<u8> {
+    pub fn has_stalemate_limit(&self) -> bool {
+        self.max_state_repetitions.is_some() || self.max_rounds_without_progress.is_some()
+    }
+
+    pub(super) fn is_stalemate(&self, repetitions: u8, rounds_without_progress: u8) -> bool {
+        let repeated = matches!(self.max_state_repetitions, Some(max) if repetitions >= max);
+        let stalled =
+            matches!(self.max_rounds_without_progress, Some(max) if rounds_without_progress >= max);
+        repeated || stalled
+    }
+
+    pub(crate) fn deductions(&self, points: Vec<u8>) -> Vec<u8> {
         if let Some(max_points) = points.iter().max() {
             if *max_points < self.points_to_win {
                 vec![0; points.len()]
@@ -34,6 +50,9 @@ impl Default for Config {
             points_to_win: 5,
             max_character_repetitions: 3,
             max_arsenal_items: 2,
+            max_state_repetitions: None,
+            max_rounds_without_progress: None,
+            move_outcomes: MoveOutcomes::default(),
         }
     }
 }
@@ -47,6 +66,50 @@ mod tests {
         let _ = Config::default();
     }
 
+    #[test]
+    fn stalemate_limits_are_off_by_default() {
+        let config = Config::default();
+        assert_eq!(None, config.max_state_repetitions);
+        assert_eq!(None, config.max_rounds_without_progress);
+    }
+
+    #[test]
+    fn is_stalemate_if_either_limit_reached() {
+        let config = Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(10),
+            ..Config::default()
+        };
+        assert!(!config.is_stalemate(2, 9));
+        assert!(config.is_stalemate(3, 0));
+        assert!(config.is_stalemate(1, 10));
+    }
+
+    #[test]
+    fn is_never_stalemate_without_limits() {
+        let config = Config {
+            max_state_repetitions: None,
+            max_rounds_without_progress: None,
+            ..Config::default()
+        };
+        assert!(!config.is_stalemate(u8::MAX, u8::MAX));
+    }
+
+    #[test]
+    fn has_stalemate_limit_if_either_limit_set() {
+        assert!(!Config::default().has_stalemate_limit());
+        assert!(Config {
+            max_state_repetitions: Some(3),
+            ..Config::default()
+        }
+        .has_stalemate_limit());
+        assert!(Config {
+            max_rounds_without_progress: Some(10),
+            ..Config::default()
+        }
+        .has_stalemate_limit());
+    }
+
     #[test]
     fn deducts_nothing_if_all_players_points_less_than_winning_amount() {
         const PLAYER_COUNT: u8 = 3;
@@ -1,7 +1,12 @@
 mod batch_choice_game;
 pub use batch_choice_game::BatchChoiceGame;
+pub(crate) use batch_choice_game::FullState;
 mod config;
 pub use config::Config;
+mod packed;
+pub use packed::PackedState;
 
 mod phase;
 use phase::Phase;
+pub use phase::PhaseKind;
+mod zobrist;
@@ -0,0 +1,501 @@
+use super::{BatchChoiceGame, Config, Phase};
+use crate::choices::{ArsenalItem, ArsenalSet, Booster, Character};
+use crate::counters::{CharacterStreak, Pool, Queue};
+use crate::players::{
+    initial_arsenal, ActionlessPlayer, BoosterlessPlayer, CharacterlessPlayer,
+    DequeueChoicelessPlayer, FinishedPlayer,
+};
+
+use std::convert::TryFrom;
+
+const PLAYER_COUNT: usize = 2;
+const LOADOUT_SIZE: usize = 6;
+
+const PHASE_BITS: u32 = 3;
+const RECHOOSE_COUNT_BITS: u32 = 16;
+const ROUNDS_WITHOUT_PROGRESS_BITS: u32 = 8;
+const STREAK_TIMES_BITS: u32 = 4;
+const POINTS_BITS: u32 = 5;
+const CHARACTER_BITS: u32 = 2;
+const BOOSTER_BITS: u32 = 4;
+const LOCATION_BITS: u32 = 3;
+
+const DESTROYED: u8 = 0;
+const ARSENAL: u8 = 1;
+const ENTRANCE: u8 = 2;
+const POOL: u8 = 3;
+const EXIT: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedState(u128);
+
+impl PackedState {
+    pub fn pack(game: &BatchChoiceGame) -> Result<PackedState, ()> {
+        if game.player_count() != PLAYER_COUNT {
+            return Err(());
+        }
+
+        let mut writer = BitWriter::default();
+        let phase = game.phase();
+        writer.write(phase_code(phase), PHASE_BITS)?;
+        writer.write(u32::from(matches!(phase, Phase::Drawn(_))), 1)?;
+        writer.write(
+            u32::from(game.character_rechoose_count()),
+            RECHOOSE_COUNT_BITS,
+        )?;
+        writer.write(
+            u32::from(game.rounds_without_progress()),
+            ROUNDS_WITHOUT_PROGRESS_BITS,
+        )?;
+
+        for i in 0..PLAYER_COUNT {
+            let streak = phase.character_streak(i);
+            writer.write(
+                u32::from(streak.as_ref().map_or(0, |s| s.times)),
+                STREAK_TIMES_BITS,
+            )?;
+            writer.write(
+                streak.map_or(0, |s| u32::from(s.character.to_index())),
+                CHARACTER_BITS,
+            )?;
+            writer.write(u32::from(phase.points(i).unwrap_or(0)), POINTS_BITS)?;
+            writer.write(
+                phase.character(i).map_or(0, |c| u32::from(c.to_index())),
+                CHARACTER_BITS,
+            )?;
+            writer.write(
+                phase.booster(i).map_or(0, |b| u32::from(b.to_index())),
+                BOOSTER_BITS,
+            )?;
+            for location in &item_locations(phase, i)? {
+                writer.write(u32::from(*location), LOCATION_BITS)?;
+            }
+        }
+
+        Ok(PackedState(writer.bits))
+    }
+
+    pub fn unpack(self, config: &Config) -> Result<BatchChoiceGame, ()> {
+        if usize::from(config.player_count) != PLAYER_COUNT {
+            return Err(());
+        }
+
+        let mut reader = BitReader::new(self.0);
+        let phase_code = reader.read(PHASE_BITS);
+        let drawn = reader.read(1) == 1;
+        let character_rechoose_count = reader.read(RECHOOSE_COUNT_BITS) as u16;
+        let rounds_without_progress = reader.read(ROUNDS_WITHOUT_PROGRESS_BITS) as u8;
+
+        let mut players = vec![];
+        for _ in 0..PLAYER_COUNT {
+            players.push(PackedPlayer::read(&mut reader)?);
+        }
+        if reader.bits != 0 {
+            return Err(());
+        }
+
+        let phase = match phase_code {
+            0 => Phase::Character(
+                players
+                    .iter()
+                    .map(|p| CharacterlessPlayer::with_streak(config.clone(), p.streak.clone()))
+                    .collect(),
+            ),
+            1 => Phase::Booster(
+                players
+                    .iter()
+                    .map(|p| BoosterlessPlayer::new(config.clone(), p.points, p.character))
+                    .collect(),
+            ),
+            2 => Phase::Dequeue(
+                players
+                    .iter()
+                    .map(|p| {
+                        let (arsenal, queue) = p.arsenal_and_queue()?;
+                        Ok(DequeueChoicelessPlayer::from_parts(
+                            config.clone(),
+                            p.points,
+                            p.character,
+                            p.booster,
+                            arsenal,
+                            queue,
+                        ))
+                    })
+                    .collect::<Result<_, ()>>()?,
+            ),
+            3 => Phase::Action(
+                players
+                    .iter()
+                    .map(|p| {
+                        let (arsenal, queue) = p.arsenal_and_queue()?;
+                        Ok(ActionlessPlayer::from_parts(
+                            config.clone(),
+                            p.points,
+                            p.character,
+                            p.booster,
+                            arsenal,
+                            queue,
+                        ))
+                    })
+                    .collect::<Result<_, ()>>()?,
+            ),
+            4 => Phase::Final(
+                players
+                    .iter()
+                    .map(|p| {
+                        let (arsenal, queue) = p.arsenal_and_queue()?;
+                        Ok(FinishedPlayer::from_parts(
+                            config.clone(),
+                            p.points,
+                            p.character,
+                            p.booster,
+                            arsenal,
+                            queue,
+                        ))
+                    })
+                    .collect::<Result<_, ()>>()?,
+            ),
+            _ => return Err(()),
+        };
+        let phase = if drawn {
+            Phase::Drawn(Box::new(phase))
+        } else {
+            phase
+        };
+
+        Ok(BatchChoiceGame::from_parts(
+            config.clone(),
+            phase,
+            character_rechoose_count,
+            rounds_without_progress,
+        ))
+    }
+
+    pub fn from_bits(bits: u128) -> PackedState {
+        PackedState(bits)
+    }
+
+    pub fn bits(self) -> u128 {
+        self.0
+    }
+}
+
+impl<'a> TryFrom<&'a BatchChoiceGame> for PackedState {
+    type Error = ();
+
+    fn try_from(game: &'a BatchChoiceGame) -> Result<PackedState, ()> {
+        PackedState::pack(game)
+    }
+}
+
+struct PackedPlayer {
+    streak: Option<CharacterStreak>,
+    points: u8,
+    character: Character,
+    booster: Booster,
+    locations: [u8; LOADOUT_SIZE],
+}
+
+impl PackedPlayer {
+    fn read(reader: &mut BitReader) -> Result<PackedPlayer, ()> {
+        let streak_times = reader.read(STREAK_TIMES_BITS) as u8;
+        let streak_character = read_character(reader)?;
+        let points = reader.read(POINTS_BITS) as u8;
+        let character = read_character(reader)?;
+        let booster = Booster::from_index(reader.read(BOOSTER_BITS) as u8).ok_or(())?;
+        let mut locations = [DESTROYED; LOADOUT_SIZE];
+        for location in &mut locations {
+            *location = reader.read(LOCATION_BITS) as u8;
+            if *location > EXIT {
+                return Err(());
+            }
+        }
+
+        Ok(PackedPlayer {
+            streak: if streak_times == 0 {
+                None
+            } else {
+                Some(CharacterStreak::new(streak_character, streak_times))
+            },
+            points,
+            character,
+            booster,
+            locations,
+        })
+    }
+
+    fn arsenal_and_queue(&self) -> Result<(ArsenalSet, Queue), ()> {
+        let mut arsenal = ArsenalSet::new();
+        let mut entrance = None;
+        let mut pool = Pool::new();
+        let mut exit = None;
+
+        let items = loadout(self.character, self.booster);
+        for (i, &location) in self.locations.iter().enumerate() {
+            let item = match items.get(i) {
+                Some(&item) => item,
+                None if location == DESTROYED => continue,
+                None => return Err(()),
+            };
+            match location {
+                ARSENAL => {
+                    arsenal.insert(item);
+                }
+                ENTRANCE if entrance.is_none() => entrance = Some(item),
+                POOL => pool.insert(item),
+                EXIT if exit.is_none() => exit = Some(item),
+                DESTROYED => {}
+                _ => return Err(()),
+            }
+        }
+
+        Ok((arsenal, Queue::from_parts(entrance, pool, exit)))
+    }
+}
+
+fn phase_code(phase: &Phase) -> u32 {
+    match phase {
+        Phase::Character(_) => 0,
+        Phase::Booster(_) => 1,
+        Phase::Dequeue(_) => 2,
+        Phase::Action(_) => 3,
+        Phase::Final(_) => 4,
+        Phase::Drawn(phase) => phase_code(phase),
+    }
+}
+
+fn loadout(character: Character, booster: Booster) -> Vec<ArsenalItem> {
+    let mut items = initial_arsenal(character, booster);
+    items.insert(ArsenalItem::Mirror);
+    items.to_vec()
+}
+
+fn item_locations(phase: &Phase, player_index: usize) -> Result<[u8; LOADOUT_SIZE], ()> {
+    let mut locations = [DESTROYED; LOADOUT_SIZE];
+    let (character, booster, queue) = match (
+        phase.character(player_index),
+        phase.booster(player_index),
+        phase.queue(player_index),
+    ) {
+        (Some(character), Some(booster), Some(queue)) => (character, booster, queue),
+        _ => return Ok(locations),
+    };
+    let arsenal = phase.arsenal(player_index).unwrap_or_default();
+    let pool = queue.pool();
+
+    let items = loadout(character, booster);
+    let mut placed = arsenal.len() + pool.len();
+    placed += usize::from(queue.entrance().is_some()) + usize::from(queue.exit().is_some());
+
+    let mut found = 0;
+    for (location, &item) in locations.iter_mut().zip(&items) {
+        let places = [
+            (arsenal.contains(item), ARSENAL),
+            (queue.entrance() == Some(item), ENTRANCE),
+            (pool.count(item) == 1, POOL),
+            (queue.exit() == Some(item), EXIT),
+        ];
+        let mut matches = places.iter().filter(|(here, _)| *here);
+        if let Some(&(_, code)) = matches.next() {
+            if matches.next().is_some() {
+                return Err(());
+            }
+            *location = code;
+            found += 1;
+        }
+    }
+
+    if found == placed {
+        Ok(locations)
+    } else {
+        Err(())
+    }
+}
+
+fn read_character(reader: &mut BitReader) -> Result<Character, ()> {
+    Character::from_index(reader.read(CHARACTER_BITS) as u8).ok_or(())
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bits: u128,
+    offset: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, width: u32) -> Result<(), ()> {
+        if u64::from(value) >= 1 << width || self.offset + width > 128 {
+            return Err(());
+        }
+        self.bits |= u128::from(value) << self.offset;
+        self.offset += width;
+        Ok(())
+    }
+}
+
+struct BitReader {
+    bits: u128,
+}
+
+impl BitReader {
+    fn new(bits: u128) -> Self {
+        Self { bits }
+    }
+
+    fn read(&mut self, width: u32) -> u32 {
+        let value = (self.bits & ((1 << width) - 1)) as u32;
+        self.bits >>= width;
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::BatchChoice;
+    use crate::random::Prng;
+
+    fn assert_round_trips(game: &BatchChoiceGame) {
+        let packed = PackedState::pack(game).unwrap();
+        let unpacked = packed.unpack(game.config()).unwrap();
+
+        assert_eq!(game, &unpacked);
+        assert_eq!(
+            game.character_rechoose_count(),
+            unpacked.character_rechoose_count()
+        );
+        assert_eq!(
+            game.rounds_without_progress(),
+            unpacked.rounds_without_progress()
+        );
+        assert_eq!(game.scoreboard(), unpacked.scoreboard());
+        assert_eq!(game.zobrist_key(), unpacked.zobrist_key());
+        assert_eq!(game.choices(), unpacked.choices());
+        assert_eq!(packed, PackedState::pack(&unpacked).unwrap());
+    }
+
+    #[test]
+    fn random_games_round_trip_at_every_step() {
+        for seed in 0..20 {
+            let mut rng = Prng::new(seed);
+            let mut game = BatchChoiceGame::default();
+            assert_round_trips(&game);
+            while !game.is_over() {
+                let choices = game
+                    .choices()
+                    .to_choices()
+                    .iter()
+                    .map(|c| c[rng.below(c.len())])
+                    .collect();
+                game.choose(BatchChoice::from_choices(choices).unwrap())
+                    .unwrap();
+                assert_round_trips(&game);
+            }
+        }
+    }
+
+    #[test]
+    fn packed_state_uses_fewer_than_128_bits() {
+        let bits = PHASE_BITS
+            + 1
+            + RECHOOSE_COUNT_BITS
+            + ROUNDS_WITHOUT_PROGRESS_BITS
+            + PLAYER_COUNT as u32
+                * (STREAK_TIMES_BITS
+                    + 2 * CHARACTER_BITS
+                    + POINTS_BITS
+                    + BOOSTER_BITS
+                    + LOADOUT_SIZE as u32 * LOCATION_BITS);
+        assert!(bits <= 128);
+    }
+
+    #[test]
+    fn same_position_reached_by_different_paths_packs_identically() {
+        let zombie_zombie = BatchChoice::Characters(vec![Character::Zombie, Character::Zombie]);
+        let mut direct = BatchChoiceGame::default();
+        direct
+            .choose(BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Ninja,
+            ]))
+            .unwrap();
+        direct.choose(zombie_zombie.clone()).unwrap();
+        let mut detour = BatchChoiceGame::default();
+        detour
+            .choose(BatchChoice::Characters(vec![
+                Character::Samurai,
+                Character::Samurai,
+            ]))
+            .unwrap();
+        detour.choose(zombie_zombie).unwrap();
+
+        assert_eq!(
+            PackedState::pack(&direct).unwrap(),
+            PackedState::pack(&detour).unwrap()
+        );
+    }
+
+    #[test]
+    fn counters_round_trip() {
+        let mut game = BatchChoiceGame::default();
+        let ninja_ninja = BatchChoice::Characters(vec![Character::Ninja, Character::Ninja]);
+        game.choose(ninja_ninja.clone()).unwrap();
+        game.choose(ninja_ninja).unwrap();
+        let unpacked = PackedState::pack(&game)
+            .unwrap()
+            .unpack(game.config())
+            .unwrap();
+
+        assert_eq!(2, unpacked.character_rechoose_count());
+        assert_eq!(2, unpacked.rounds_without_progress());
+        assert_eq!(game.full_state(), unpacked.full_state());
+    }
+
+    #[test]
+    fn different_counters_pack_differently() {
+        let ninja_ninja = BatchChoice::Characters(vec![Character::Ninja, Character::Ninja]);
+        let mut once = BatchChoiceGame::default();
+        once.choose(ninja_ninja.clone()).unwrap();
+        let mut twice = BatchChoiceGame::default();
+        twice
+            .choose(BatchChoice::Characters(vec![
+                Character::Zombie,
+                Character::Zombie,
+            ]))
+            .unwrap();
+        twice.choose(ninja_ninja).unwrap();
+
+        assert_eq!(once, twice);
+        assert_ne!(
+            PackedState::pack(&once).unwrap(),
+            PackedState::pack(&twice).unwrap()
+        );
+    }
+
+    #[test]
+    fn other_player_counts_are_rejected() {
+        let config = Config {
+            player_count: 3,
+            ..Config::default()
+        };
+        assert!(PackedState::pack(&BatchChoiceGame::new(config.clone())).is_err());
+        let packed = PackedState::pack(&BatchChoiceGame::default()).unwrap();
+        assert!(packed.unpack(&config).is_err());
+    }
+
+    #[test]
+    fn invalid_bits_are_rejected() {
+        assert!(PackedState::from_bits(7)
+            .unpack(&Config::default())
+            .is_err());
+        assert!(PackedState::from_bits(u128::MAX)
+            .unpack(&Config::default())
+            .is_err());
+    }
+
+    #[test]
+    fn try_from_matches_pack() {
+        let game = BatchChoiceGame::default();
+        assert_eq!(PackedState::pack(&game), PackedState::try_from(&game));
+    }
+}
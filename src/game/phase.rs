@@ -1,9 +1,10 @@
 use crate::{
+    choices::{ArsenalSet, Booster, Character},
     players::{
         ActionlessPlayer, BoosterlessPlayer, CharacterlessPlayer, DequeueChoicelessPlayer,
         FinishedPlayer,
     },
-    scoreboard::Scoreboard,
+    scoreboard::{CharacterStreak, QueueRef, Scoreboard},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -13,11 +14,108 @@ pub(super) enum Phase {
     Dequeue(Vec<DequeueChoicelessPlayer>),
     Action(Vec<ActionlessPlayer>),
     Final(Vec<FinishedPlayer>),
+    Drawn(Box<Phase>),
 }
 
-impl Into<Scoreboard> for Phase {
-    fn into(self) -> Scoreboard {
+impl Phase {
+    pub(super) fn kind(&self) -> PhaseKind {
         match self {
+            Phase::Character(_) => PhaseKind::Character,
+            Phase::Booster(_) => PhaseKind::Booster,
+            Phase::Dequeue(_) => PhaseKind::Dequeue,
+            Phase::Action(_) => PhaseKind::Action,
+            Phase::Final(_) | Phase::Drawn(_) => PhaseKind::Final,
+        }
+    }
+
+    pub(super) fn player_count(&self) -> usize {
+        match self {
+            Phase::Character(players) => players.len(),
+            Phase::Booster(players) => players.len(),
+            Phase::Dequeue(players) => players.len(),
+            Phase::Action(players) => players.len(),
+            Phase::Final(players) => players.len(),
+            Phase::Drawn(phase) => phase.player_count(),
+        }
+    }
+
+    pub(super) fn points(&self, player_index: usize) -> Option<u8> {
+        match self {
+            Phase::Character(_) => None,
+            Phase::Booster(players) => players.get(player_index).map(|p| p.points()),
+            Phase::Dequeue(players) => players.get(player_index).map(|p| p.points()),
+            Phase::Action(players) => players.get(player_index).map(|p| p.points()),
+            Phase::Final(players) => players.get(player_index).map(|p| p.points()),
+            Phase::Drawn(phase) => phase.points(player_index),
+        }
+    }
+
+    pub(super) fn character(&self, player_index: usize) -> Option<Character> {
+        match self {
+            Phase::Character(_) => None,
+            Phase::Booster(players) => players.get(player_index).map(|p| p.character()),
+            Phase::Dequeue(players) => players.get(player_index).map(|p| p.character()),
+            Phase::Action(players) => players.get(player_index).map(|p| p.character()),
+            Phase::Final(players) => players.get(player_index).map(|p| p.character()),
+            Phase::Drawn(phase) => phase.character(player_index),
+        }
+    }
+
+    pub(super) fn booster(&self, player_index: usize) -> Option<Booster> {
+        match self {
+            Phase::Character(_) | Phase::Booster(_) => None,
+            Phase::Dequeue(players) => players.get(player_index).map(|p| p.booster()),
+            Phase::Action(players) => players.get(player_index).map(|p| p.booster()),
+            Phase::Final(players) => players.get(player_index).map(|p| p.booster()),
+            Phase::Drawn(phase) => phase.booster(player_index),
+        }
+    }
+
+    pub(super) fn arsenal(&self, player_index: usize) -> Option<ArsenalSet> {
+        match self {
+            Phase::Character(_) | Phase::Booster(_) => None,
+            Phase::Dequeue(players) => players.get(player_index).map(|p| p.arsenal()),
+            Phase::Action(players) => players.get(player_index).map(|p| p.arsenal()),
+            Phase::Final(players) => players.get(player_index).map(|p| p.arsenal()),
+            Phase::Drawn(phase) => phase.arsenal(player_index),
+        }
+    }
+
+    pub(super) fn queue(&self, player_index: usize) -> Option<QueueRef<'_>> {
+        let queue = match self {
+            Phase::Character(_) | Phase::Booster(_) => None,
+            Phase::Dequeue(players) => players.get(player_index).map(|p| p.queue()),
+            Phase::Action(players) => players.get(player_index).map(|p| p.queue()),
+            Phase::Final(players) => players.get(player_index).map(|p| p.queue()),
+            Phase::Drawn(phase) => return phase.queue(player_index),
+        };
+        queue.map(QueueRef::new)
+    }
+
+    pub(super) fn character_streak(&self, player_index: usize) -> Option<CharacterStreak> {
+        match self {
+            Phase::Character(players) => players
+                .get(player_index)
+                .and_then(|p| p.streak())
+                .map(|streak| streak.clone().into()),
+            Phase::Drawn(phase) => phase.character_streak(player_index),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhaseKind {
+    Character,
+    Booster,
+    Dequeue,
+    Action,
+    Final,
+}
+
+impl From<Phase> for Scoreboard {
+    fn from(phase: Phase) -> Scoreboard {
+        match phase {
             Phase::Character(players) => {
                 Scoreboard::Characterless(players.into_iter().map(|p| p.into()).collect())
             }
@@ -33,6 +131,7 @@ impl Into<Scoreboard> for Phase {
             Phase::Final(players) => {
                 Scoreboard::Final(players.into_iter().map(|p| p.into()).collect())
             }
+            Phase::Drawn(phase) => Scoreboard::Drawn(Box::new((*phase).into())),
         }
     }
 }
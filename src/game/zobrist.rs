@@ -0,0 +1,328 @@
+use super::{Phase, PhaseKind};
+use crate::choices::{ArsenalItem, ArsenalSet, Booster, Character};
+use crate::counters::Queue;
+use crate::players::{
+    ActionlessPlayer, BoosterlessPlayer, CharacterlessPlayer, DequeueChoicelessPlayer,
+    FinishedPlayer,
+};
+use crate::random;
+use crate::scoreboard::{CharacterStreak, Pool};
+
+const SEED: u64 = 0x6e7a_7363_7a6f_6272;
+
+const PHASE: u8 = 0;
+const POINTS: u8 = 1;
+const CHARACTER: u8 = 2;
+const BOOSTER: u8 = 3;
+const ARSENAL: u8 = 4;
+const ENTRANCE: u8 = 5;
+const POOL: u8 = 6;
+const EXIT: u8 = 7;
+const STREAK: u8 = 8;
+
+const KINDS: usize = 9;
+const TABLE_PLAYERS: usize = 4;
+const TABLE_VALUES: usize = 64;
+const STREAK_TIMES: u32 = 16;
+
+static TABLE: [[[u64; TABLE_VALUES]; KINDS]; TABLE_PLAYERS] = table();
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct PlayerFeatures {
+    points: Option<u8>,
+    character: Option<Character>,
+    booster: Option<Booster>,
+    arsenal: ArsenalSet,
+    entrance: Option<ArsenalItem>,
+    pool: Pool,
+    exit: Option<ArsenalItem>,
+    streak: Option<(Character, u8)>,
+}
+
+impl PlayerFeatures {
+    pub(super) fn of(phase: &Phase, player_index: usize) -> Self {
+        let features = match phase {
+            Phase::Character(players) => players.get(player_index).map(Self::from),
+            Phase::Booster(players) => players.get(player_index).map(Self::from),
+            Phase::Dequeue(players) => players.get(player_index).map(Self::from),
+            Phase::Action(players) => players.get(player_index).map(Self::from),
+            Phase::Final(players) => players.get(player_index).map(Self::from),
+            Phase::Drawn(phase) => return Self::of(phase, player_index),
+        };
+        features.unwrap_or_default()
+    }
+
+    pub(super) fn all(phase: &Phase) -> Vec<Self> {
+        (0..phase.player_count())
+            .map(|i| Self::of(phase, i))
+            .collect()
+    }
+
+    fn queued(
+        points: u8,
+        character: Character,
+        booster: Booster,
+        arsenal: ArsenalSet,
+        queue: &Queue,
+    ) -> Self {
+        Self {
+            points: Some(points),
+            character: Some(character),
+            booster: Some(booster),
+            arsenal,
+            entrance: queue.entrance(),
+            pool: *queue.pool(),
+            exit: queue.exit(),
+            streak: None,
+        }
+    }
+}
+
+impl From<&CharacterlessPlayer> for PlayerFeatures {
+    fn from(player: &CharacterlessPlayer) -> Self {
+        let streak = player.streak().map(|streak| {
+            let streak: CharacterStreak = streak.clone().into();
+            (streak.character, streak.times)
+        });
+        Self {
+            streak,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&BoosterlessPlayer> for PlayerFeatures {
+    fn from(player: &BoosterlessPlayer) -> Self {
+        Self {
+            points: Some(player.points()),
+            character: Some(player.character()),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&DequeueChoicelessPlayer> for PlayerFeatures {
+    fn from(player: &DequeueChoicelessPlayer) -> Self {
+        Self::queued(
+            player.points(),
+            player.character(),
+            player.booster(),
+            player.arsenal(),
+            player.queue(),
+        )
+    }
+}
+
+impl From<&ActionlessPlayer> for PlayerFeatures {
+    fn from(player: &ActionlessPlayer) -> Self {
+        Self::queued(
+            player.points(),
+            player.character(),
+            player.booster(),
+            player.arsenal(),
+            player.queue(),
+        )
+    }
+}
+
+impl From<&FinishedPlayer> for PlayerFeatures {
+    fn from(player: &FinishedPlayer) -> Self {
+        Self::queued(
+            player.points(),
+            player.character(),
+            player.booster(),
+            player.arsenal(),
+            player.queue(),
+        )
+    }
+}
+
+pub(super) fn phase_key(kind: PhaseKind) -> u64 {
+    let value = match kind {
+        PhaseKind::Character => 0,
+        PhaseKind::Booster => 1,
+        PhaseKind::Dequeue => 2,
+        PhaseKind::Action => 3,
+        PhaseKind::Final => 4,
+    };
+    feature(0, PHASE, value)
+}
+
+pub(super) fn game_key(kind: PhaseKind, players: &[PlayerFeatures]) -> u64 {
+    let empty = PlayerFeatures::default();
+    players
+        .iter()
+        .enumerate()
+        .fold(phase_key(kind), |key, (i, player)| {
+            key ^ player_delta(i, &empty, player)
+        })
+}
+
+pub(super) fn player_delta(player_index: usize, old: &PlayerFeatures, new: &PlayerFeatures) -> u64 {
+    let player = player_index as u64;
+    let mut delta = 0;
+
+    if old.points != new.points {
+        delta ^= optional(player, POINTS, old.points.map(u32::from));
+        delta ^= optional(player, POINTS, new.points.map(u32::from));
+    }
+    if old.character != new.character {
+        delta ^= optional(
+            player,
+            CHARACTER,
+            old.character.map(|c| u32::from(c.to_index())),
+        );
+        delta ^= optional(
+            player,
+            CHARACTER,
+            new.character.map(|c| u32::from(c.to_index())),
+        );
+    }
+    if old.booster != new.booster {
+        delta ^= optional(
+            player,
+            BOOSTER,
+            old.booster.map(|b| u32::from(b.to_index())),
+        );
+        delta ^= optional(
+            player,
+            BOOSTER,
+            new.booster.map(|b| u32::from(b.to_index())),
+        );
+    }
+
+    let changed =
+        ArsenalSet::from_bits(old.arsenal.bits() ^ new.arsenal.bits()).unwrap_or_default();
+    for item in changed {
+        delta ^= feature(player, ARSENAL, u32::from(item.to_index()));
+    }
+
+    if old.entrance != new.entrance {
+        delta ^= optional(player, ENTRANCE, old.entrance.map(item_index));
+        delta ^= optional(player, ENTRANCE, new.entrance.map(item_index));
+    }
+    if old.pool != new.pool {
+        let items = old.pool.items().bits() | new.pool.items().bits();
+        for item in ArsenalSet::from_bits(items).unwrap_or_default() {
+            let (before, after) = (old.pool.count(item), new.pool.count(item));
+            if before != after {
+                delta ^= pool_count(player, item, before) ^ pool_count(player, item, after);
+            }
+        }
+    }
+    if old.exit != new.exit {
+        delta ^= optional(player, EXIT, old.exit.map(item_index));
+        delta ^= optional(player, EXIT, new.exit.map(item_index));
+    }
+    if old.streak != new.streak {
+        let streak = |s: Option<(Character, u8)>| {
+            s.map(|(c, times)| u32::from(c.to_index()) << 8 | u32::from(times))
+        };
+        delta ^= optional(player, STREAK, streak(old.streak));
+        delta ^= optional(player, STREAK, streak(new.streak));
+    }
+
+    delta
+}
+
+fn item_index(item: ArsenalItem) -> u32 {
+    u32::from(item.to_index())
+}
+
+fn pool_count(player: u64, item: ArsenalItem, count: u8) -> u64 {
+    if count == 0 {
+        0
+    } else {
+        feature(player, POOL, item_index(item) << 8 | u32::from(count))
+    }
+}
+
+fn optional(player: u64, kind: u8, value: Option<u32>) -> u64 {
+    value.map_or(0, |value| feature(player, kind, value))
+}
+
+fn feature(player: u64, kind: u8, value: u32) -> u64 {
+    match table_index(kind, value) {
+        Some(index) if player < TABLE_PLAYERS as u64 => {
+            TABLE[player as usize][usize::from(kind)][index]
+        }
+        _ => computed_feature(player, kind, value),
+    }
+}
+
+const fn computed_feature(player: u64, kind: u8, value: u32) -> u64 {
+    random::first_output(SEED ^ (player << 40 | (kind as u64) << 32 | value as u64))
+}
+
+fn table_index(kind: u8, value: u32) -> Option<usize> {
+    let index = match kind {
+        POOL if value & 0xff == 1 => value >> 8,
+        POOL => return None,
+        STREAK if value & 0xff < STREAK_TIMES => (value >> 8) * STREAK_TIMES + (value & 0xff),
+        STREAK => return None,
+        _ => value,
+    };
+    if (index as usize) < TABLE_VALUES {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+const fn table_value(kind: u8, index: u32) -> u32 {
+    match kind {
+        POOL => index << 8 | 1,
+        STREAK => ((index / STREAK_TIMES) << 8) | (index % STREAK_TIMES),
+        _ => index,
+    }
+}
+
+const fn table() -> [[[u64; TABLE_VALUES]; KINDS]; TABLE_PLAYERS] {
+    let mut table = [[[0; TABLE_VALUES]; KINDS]; TABLE_PLAYERS];
+    let mut player = 0;
+    while player < TABLE_PLAYERS {
+        let mut kind = 0;
+        while kind < KINDS {
+            let mut index = 0;
+            while index < TABLE_VALUES {
+                let value = table_value(kind as u8, index as u32);
+                table[player][kind][index] = computed_feature(player as u64, kind as u8, value);
+                index += 1;
+            }
+            kind += 1;
+        }
+        player += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_matches_computed_features() {
+        for player in 0..TABLE_PLAYERS as u64 {
+            for kind in 0..KINDS as u8 {
+                for index in 0..TABLE_VALUES as u32 {
+                    let value = table_value(kind, index);
+                    assert_eq!(Some(index as usize), table_index(kind, value));
+                    assert_eq!(
+                        computed_feature(player, kind, value),
+                        feature(player, kind, value)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn values_outside_table_are_computed() {
+        let pool_pair = u32::from(ArsenalItem::Mirror.to_index()) << 8 | 2;
+        assert_eq!(None, table_index(POOL, pool_pair));
+        assert_eq!(
+            computed_feature(TABLE_PLAYERS as u64, POINTS, 3),
+            feature(TABLE_PLAYERS as u64, POINTS, 3)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use std::collections::HashSet;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 
 pub fn lowercase_no_whitespace(s: &str) -> String {
     let bytes = s.as_bytes();
@@ -28,6 +28,45 @@ impl<T: Eq + Hash> HasDuplicates for Vec<T> {
     }
 }
 
+pub fn cartesian_product<T: Clone>(sets: &[Vec<T>]) -> Vec<Vec<T>> {
+    let mut product: Vec<Vec<T>> = vec![vec![]];
+    for set in sets {
+        product = product
+            .into_iter()
+            .flat_map(|prefix| {
+                set.iter().map(move |item| {
+                    let mut tuple = prefix.clone();
+                    tuple.push(item.clone());
+                    tuple
+                })
+            })
+            .collect();
+    }
+    product
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> StableHasher {
+        StableHasher(0xCBF2_9CE4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01B3);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +82,27 @@ mod tests {
         assert!(fibonacci.has_duplicates());
     }
 
+    #[test]
+    fn stable_hasher_is_fnv_1a() {
+        let mut hasher = StableHasher::default();
+        hasher.write(b"a");
+        assert_eq!(0xAF63_DC4C_8601_EC8C, hasher.finish());
+    }
+
+    #[test]
+    fn cartesian_product_works() {
+        assert_eq!(
+            vec![vec![1, 3], vec![1, 4], vec![2, 3], vec![2, 4]],
+            cartesian_product(&[vec![1, 2], vec![3, 4]])
+        );
+    }
+
+    #[test]
+    fn cartesian_product_of_nothing_is_one_empty_tuple() {
+        let empty: Vec<Vec<u8>> = vec![];
+        assert_eq!(vec![Vec::<u8>::new()], cartesian_product(&empty));
+    }
+
     #[test]
     fn abc_does_not_have_duplicates() {
         let abc: Vec<char> = vec!['a', 'b', 'c'];
@@ -1,9 +1,16 @@
 #![warn(clippy::all)]
+#![allow(clippy::result_unit_err)]
 
+pub mod agents;
+pub mod analysis;
 pub mod choices;
+pub mod env;
 pub mod game;
 pub mod outcomes;
+pub mod random;
 pub mod scoreboard;
+pub mod simulation;
+pub mod solver;
 
 pub mod prelude {
     pub use crate::{choices::*, game::*, outcomes::*};
@@ -8,6 +8,7 @@ pub enum Outcome {
     DequeuePhaseDone(Vec<DequeueChoice>),
     ActionPhaseDone(Vec<ActionPointsDestroyed>),
     GameOver(Vec<ActionPointsDestroyed>),
+    Draw(Vec<ActionPointsDestroyed>),
 }
 
 impl Outcome {
@@ -58,6 +59,14 @@ impl Outcome {
             None
         }
     }
+
+    pub fn draw(self) -> Option<Vec<ActionPointsDestroyed>> {
+        if let Outcome::Draw(apd) = self {
+            Some(apd)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -145,6 +154,16 @@ mod tests {
 
     #[test]
     fn game_over_returns_none_if_outcome_is_not_game_over() {
-        assert!(Outcome::ActionPhaseDone(vec![]).game_over().is_none());
+        assert!(Outcome::Draw(vec![]).game_over().is_none());
+    }
+
+    #[test]
+    fn draw_returns_some_if_outcome_is_draw() {
+        assert!(Outcome::Draw(vec![]).draw().is_some());
+    }
+
+    #[test]
+    fn draw_returns_none_if_outcome_is_not_draw() {
+        assert!(Outcome::GameOver(vec![]).draw().is_none());
     }
 }
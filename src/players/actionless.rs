@@ -1,5 +1,8 @@
 use super::{DequeueChoicelessPlayer, FinishedPlayer};
-use crate::choices::{Action, ArsenalItem, Booster, Character, Choose};
+use crate::choices::{
+    Action, ArsenalItem, ArsenalSet, Booster, Character, Choose, ExplainIllegal, IllegalChoice,
+    LegalActions,
+};
 use crate::counters::Queue;
 use crate::game::Config;
 use crate::outcomes::ActionPointsDestroyed;
@@ -11,15 +14,53 @@ pub struct ActionlessPlayer {
     pub(super) points: u8,
     pub(super) character: Character,
     pub(super) booster: Booster,
-    pub(super) arsenal: Vec<ArsenalItem>,
+    pub(super) arsenal: ArsenalSet,
     pub(super) queue: Queue,
 }
 
 impl ActionlessPlayer {
+    pub(crate) fn from_parts(
+        game_config: Config,
+        points: u8,
+        character: Character,
+        booster: Booster,
+        arsenal: ArsenalSet,
+        queue: Queue,
+    ) -> Self {
+        Self {
+            game_config,
+            points,
+            character,
+            booster,
+            arsenal,
+            queue,
+        }
+    }
+
     pub fn points(&self) -> u8 {
         self.points
     }
 
+    pub fn character(&self) -> Character {
+        self.character
+    }
+
+    pub fn booster(&self) -> Booster {
+        self.booster
+    }
+
+    pub fn arsenal(&self) -> ArsenalSet {
+        self.arsenal
+    }
+
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+
+    pub fn legal_actions(&self) -> LegalActions {
+        LegalActions::new(self.arsenal, self.queue.pool().items())
+    }
+
     pub fn into_dequeue_choiceless(
         mut self,
         ActionPointsDestroyed(action, points, action_destroyed): ActionPointsDestroyed,
@@ -56,8 +97,8 @@ impl ActionlessPlayer {
 
     fn use_action(&mut self, action: Action, action_destroyed: bool) {
         let arsenal_item: Option<ArsenalItem> = action.into();
-        if let Some(arsenal_item) = &arsenal_item {
-            self.arsenal.retain(|m| m != arsenal_item);
+        if let Some(arsenal_item) = arsenal_item {
+            self.arsenal.remove(arsenal_item);
         }
         if !action_destroyed {
             self.queue.enqueue(arsenal_item);
@@ -72,41 +113,48 @@ impl ActionlessPlayer {
 
 impl Choose<Action> for ActionlessPlayer {
     fn choices(&self) -> Vec<Action> {
-        let has_mirror = self.arsenal.contains(&ArsenalItem::Mirror);
-        let mut actions: Vec<Action> = vec![];
-        let move_actions: Vec<Action> = self
-            .arsenal
-            .iter()
-            .filter(|item| item != &&ArsenalItem::Mirror)
-            .map(|item| item.as_move_action().unwrap())
-            .collect();
-        actions.extend(move_actions);
-        if has_mirror {
-            let mirror_actions: Vec<Action> = self
-                .queue
-                .pool()
-                .clone()
-                .into_iter()
-                .map(|item| item.as_mirror_action().unwrap())
-                .collect();
-            actions.extend(mirror_actions);
-        }
-        if actions.is_empty() {
-            actions.push(Action::Concede);
-        }
+        self.legal_actions().collect()
+    }
+}
 
-        actions
+impl ExplainIllegal<Action> for ActionlessPlayer {
+    fn explain_illegal(&self, action: &Action) -> Option<IllegalChoice> {
+        match action {
+            Action::Move(m) => {
+                if self.arsenal.contains(ArsenalItem::Move(*m)) {
+                    None
+                } else {
+                    Some(IllegalChoice::NotInArsenal(ArsenalItem::Move(*m)))
+                }
+            }
+            Action::Mirror(m) => {
+                if !self.arsenal.has_mirror() {
+                    Some(IllegalChoice::NotInArsenal(ArsenalItem::Mirror))
+                } else if !self.queue.pool().contains(ArsenalItem::Move(*m)) {
+                    Some(IllegalChoice::NotInPool(ArsenalItem::Move(*m)))
+                } else {
+                    None
+                }
+            }
+            Action::Concede => {
+                if self.legal_actions().eq(Some(Action::Concede)) {
+                    None
+                } else {
+                    Some(IllegalChoice::ConcedeUnavailable)
+                }
+            }
+        }
     }
 }
 
-impl Into<transparent::ActionlessPlayer> for ActionlessPlayer {
-    fn into(self) -> transparent::ActionlessPlayer {
+impl From<ActionlessPlayer> for transparent::ActionlessPlayer {
+    fn from(player: ActionlessPlayer) -> transparent::ActionlessPlayer {
         transparent::ActionlessPlayer {
-            points: self.points,
-            character: self.character,
-            booster: self.booster,
-            arsenal: self.arsenal,
-            queue: self.queue.into(),
+            points: player.points,
+            character: player.character,
+            booster: player.booster,
+            arsenal: player.arsenal.to_vec(),
+            queue: player.queue.into(),
         }
     }
 }
@@ -169,6 +217,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explain_illegal_reports_missing_arsenal_items() {
+        use crate::choices::Move;
+
+        let mut shadow = actionless_shadow();
+        assert_eq!(None, shadow.explain_illegal(&Action::Move(Move::Kick)));
+        assert_eq!(
+            Some(IllegalChoice::NotInArsenal(ArsenalItem::Move(Move::Zap))),
+            shadow.explain_illegal(&Action::Move(Move::Zap))
+        );
+        assert_eq!(
+            Some(IllegalChoice::ConcedeUnavailable),
+            shadow.explain_illegal(&Action::Concede)
+        );
+
+        shadow.arsenal = [ArsenalItem::Mirror][..].into();
+        assert_eq!(
+            Some(IllegalChoice::NotInPool(ArsenalItem::Move(Move::Kick))),
+            shadow.explain_illegal(&Action::Mirror(Move::Kick))
+        );
+        shadow.arsenal = ArsenalSet::new();
+        assert_eq!(
+            Some(IllegalChoice::NotInArsenal(ArsenalItem::Mirror)),
+            shadow.explain_illegal(&Action::Mirror(Move::Kick))
+        );
+        assert_eq!(None, shadow.explain_illegal(&Action::Concede));
+    }
+
     #[test]
     fn add_points_adds_points_if_new_points_is_greater_than_zero() {
         let mut shadow = actionless_shadow();
@@ -254,7 +330,7 @@ mod tests {
         assert_eq!(original.points, transparent.points);
         assert_eq!(original.character, transparent.character);
         assert_eq!(original.booster, transparent.booster);
-        assert_eq!(original.arsenal, transparent.arsenal);
+        assert_eq!(original.arsenal.to_vec(), transparent.arsenal);
         assert_eq!(
             Into::<transparent::Queue>::into(original.queue),
             transparent.queue
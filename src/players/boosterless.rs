@@ -1,5 +1,7 @@
 use super::dequeue_choiceless::DequeueChoicelessPlayer;
-use crate::choices::{ArsenalItem, Booster, Character, Choose};
+use crate::choices::{
+    ArsenalItem, ArsenalSet, Booster, Character, Choose, ExplainIllegal, IllegalChoice,
+};
 use crate::counters::Queue;
 use crate::game::Config;
 use crate::scoreboard::transparent;
@@ -12,6 +14,22 @@ pub struct BoosterlessPlayer {
 }
 
 impl BoosterlessPlayer {
+    pub(crate) fn new(game_config: Config, points: u8, character: Character) -> Self {
+        Self {
+            game_config,
+            points,
+            character,
+        }
+    }
+
+    pub fn points(&self) -> u8 {
+        self.points
+    }
+
+    pub fn character(&self) -> Character {
+        self.character
+    }
+
     pub fn into_dequeue_choiceless(self, booster: Booster) -> DequeueChoicelessPlayer {
         let arsenal = self.initial_arsenal(booster);
 
@@ -25,27 +43,40 @@ impl BoosterlessPlayer {
         }
     }
 
-    fn initial_arsenal(&self, booster: Booster) -> Vec<ArsenalItem> {
-        let mut moves = self.character.moves();
-        moves.extend(booster.moves());
-        booster.replace_moves(&mut moves);
-        let arsenal: Vec<ArsenalItem> = moves.into_iter().map(ArsenalItem::Move).collect();
-
-        arsenal
+    fn initial_arsenal(&self, booster: Booster) -> ArsenalSet {
+        initial_arsenal(self.character, booster)
     }
 }
 
+pub(crate) fn initial_arsenal(character: Character, booster: Booster) -> ArsenalSet {
+    let mut moves = character.moves();
+    moves.extend(booster.moves());
+    booster.replace_moves(&mut moves);
+
+    moves.into_iter().map(ArsenalItem::Move).collect()
+}
+
 impl Choose<Booster> for BoosterlessPlayer {
     fn choices(&self) -> Vec<Booster> {
         self.character.boosters()
     }
 }
 
-impl Into<transparent::BoosterlessPlayer> for BoosterlessPlayer {
-    fn into(self) -> transparent::BoosterlessPlayer {
+impl ExplainIllegal<Booster> for BoosterlessPlayer {
+    fn explain_illegal(&self, booster: &Booster) -> Option<IllegalChoice> {
+        if self.choices().contains(booster) {
+            None
+        } else {
+            Some(IllegalChoice::BoosterUnavailable(self.character, *booster))
+        }
+    }
+}
+
+impl From<BoosterlessPlayer> for transparent::BoosterlessPlayer {
+    fn from(player: BoosterlessPlayer) -> transparent::BoosterlessPlayer {
         transparent::BoosterlessPlayer {
-            points: self.points,
-            character: self.character,
+            points: player.points,
+            character: player.character,
         }
     }
 }
@@ -81,6 +112,19 @@ mod tests {
         assert!(!player.choices().contains(&Booster::Atlas));
     }
 
+    #[test]
+    fn explain_illegal_reports_unavailable_booster() {
+        let player = ninja();
+        assert_eq!(None, player.explain_illegal(&Booster::Shadow));
+        assert_eq!(
+            Some(IllegalChoice::BoosterUnavailable(
+                Character::Ninja,
+                Booster::Atlas
+            )),
+            player.explain_illegal(&Booster::Atlas)
+        );
+    }
+
     #[test]
     fn shadow_initial_arsenal_is_correct() {
         use crate::choices::Move;
@@ -88,13 +132,13 @@ mod tests {
         let player = ninja();
         let arsenal = player.initial_arsenal(Booster::Shadow);
         assert_eq!(
-            arsenal,
+            arsenal.to_vec(),
             vec![
                 ArsenalItem::Move(Move::Kick),
                 ArsenalItem::Move(Move::NinjaSword),
                 ArsenalItem::Move(Move::Nunchucks),
                 ArsenalItem::Move(Move::ShadowFireball),
-                ArsenalItem::Move(Move::ShadowSlip)
+                ArsenalItem::Move(Move::ShadowSlip),
             ]
         );
     }
@@ -106,13 +150,13 @@ mod tests {
         let player = samurai();
         let arsenal = player.initial_arsenal(Booster::Atlas);
         assert_eq!(
-            arsenal,
+            arsenal.to_vec(),
             vec![
                 ArsenalItem::Move(Move::SamuraiSword),
                 ArsenalItem::Move(Move::Helmet),
                 ArsenalItem::Move(Move::Smash),
                 ArsenalItem::Move(Move::Lightning),
-                ArsenalItem::Move(Move::Earthquake)
+                ArsenalItem::Move(Move::Earthquake),
             ]
         );
     }
@@ -124,13 +168,13 @@ mod tests {
         let player = samurai();
         let arsenal = player.initial_arsenal(Booster::Strong);
         assert_eq!(
-            arsenal,
+            arsenal.to_vec(),
             vec![
                 ArsenalItem::Move(Move::SamuraiSword),
                 ArsenalItem::Move(Move::Helmet),
+                ArsenalItem::Move(Move::StrongSmash),
                 ArsenalItem::Move(Move::Twist),
                 ArsenalItem::Move(Move::Bend),
-                ArsenalItem::Move(Move::StrongSmash),
             ]
         );
     }
@@ -1,5 +1,5 @@
 use super::BoosterlessPlayer;
-use crate::choices::{Character, Choose};
+use crate::choices::{CanChoose, Character, Choose, ExplainIllegal, IllegalChoice};
 use crate::counters::{CharacterChoices, CharacterStreak};
 use crate::game::Config;
 use crate::outcomes::CharacterHeadstart;
@@ -19,6 +19,17 @@ impl CharacterlessPlayer {
         }
     }
 
+    pub(crate) fn with_streak(game_config: Config, streak: Option<CharacterStreak>) -> Self {
+        Self {
+            game_config,
+            streak,
+        }
+    }
+
+    pub(crate) fn streak(&self) -> Option<&CharacterStreak> {
+        self.streak.as_ref()
+    }
+
     pub fn add_to_streak(&mut self, character: Character) {
         self.streak
             .choose(self.game_config.max_character_repetitions, character)
@@ -44,10 +55,21 @@ impl Choose<Character> for CharacterlessPlayer {
     }
 }
 
-impl Into<transparent::CharacterlessPlayer> for CharacterlessPlayer {
-    fn into(self) -> transparent::CharacterlessPlayer {
+impl ExplainIllegal<Character> for CharacterlessPlayer {
+    fn explain_illegal(&self, character: &Character) -> Option<IllegalChoice> {
+        if self.can_choose(character) {
+            None
+        } else {
+            let streak: transparent::CharacterStreak = self.streak.clone()?.into();
+            Some(IllegalChoice::CharacterRepeated(*character, streak.times))
+        }
+    }
+}
+
+impl From<CharacterlessPlayer> for transparent::CharacterlessPlayer {
+    fn from(player: CharacterlessPlayer) -> transparent::CharacterlessPlayer {
         transparent::CharacterlessPlayer {
-            streak: self.streak.map(|streak| streak.into()),
+            streak: player.streak.map(|streak| streak.into()),
         }
     }
 }
@@ -101,6 +123,24 @@ mod tests {
         assert_eq!(no_ninja, player.choices());
     }
 
+    #[test]
+    fn explain_illegal_reports_repeated_character() {
+        let mut player = CharacterlessPlayer::from_game_config(Config::default());
+        assert_eq!(None, player.explain_illegal(&Character::Ninja));
+        for _ in 0..player.game_config.max_character_repetitions {
+            player.add_to_streak(Character::Ninja);
+        }
+
+        assert_eq!(
+            Some(IllegalChoice::CharacterRepeated(
+                Character::Ninja,
+                player.game_config.max_character_repetitions
+            )),
+            player.explain_illegal(&Character::Ninja)
+        );
+        assert_eq!(None, player.explain_illegal(&Character::Zombie));
+    }
+
     #[test]
     fn into_boosterless_works() {
         let player = CharacterlessPlayer::from_game_config(Config::default());
@@ -1,5 +1,8 @@
-use super::ActionlessPlayer;
-use crate::choices::{ArsenalItem, Booster, Character, Choose, DequeueChoice};
+use super::{ActionlessPlayer, FinishedPlayer};
+use crate::choices::{
+    ArsenalSet, Booster, Character, Choose, DequeueChoice, ExplainIllegal, IllegalChoice,
+    LegalDequeueChoices,
+};
 use crate::counters::Queue;
 use crate::game::Config;
 use crate::scoreboard::transparent;
@@ -10,14 +13,56 @@ pub struct DequeueChoicelessPlayer {
     pub(super) points: u8,
     pub(super) character: Character,
     pub(super) booster: Booster,
-    pub(super) arsenal: Vec<ArsenalItem>,
+    pub(super) arsenal: ArsenalSet,
     pub(super) queue: Queue,
 }
 
 impl DequeueChoicelessPlayer {
+    pub(crate) fn from_parts(
+        game_config: Config,
+        points: u8,
+        character: Character,
+        booster: Booster,
+        arsenal: ArsenalSet,
+        queue: Queue,
+    ) -> Self {
+        Self {
+            game_config,
+            points,
+            character,
+            booster,
+            arsenal,
+            queue,
+        }
+    }
+
+    pub fn points(&self) -> u8 {
+        self.points
+    }
+
+    pub fn character(&self) -> Character {
+        self.character
+    }
+
+    pub fn booster(&self) -> Booster {
+        self.booster
+    }
+
+    pub fn arsenal(&self) -> ArsenalSet {
+        self.arsenal
+    }
+
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+
+    pub fn legal_dequeue_choices(&self) -> LegalDequeueChoices {
+        LegalDequeueChoices::new(self.queue.pool().items(), self.can_dequeue())
+    }
+
     pub fn into_actionless(mut self, dequeue_choice: DequeueChoice) -> ActionlessPlayer {
         if let Some(arsenal_item) = self.queue.dequeue(dequeue_choice).unwrap() {
-            self.arsenal.push(arsenal_item);
+            self.arsenal.insert(arsenal_item);
         }
 
         ActionlessPlayer {
@@ -30,6 +75,17 @@ impl DequeueChoicelessPlayer {
         }
     }
 
+    pub fn into_finished(self) -> FinishedPlayer {
+        FinishedPlayer {
+            game_config: self.game_config,
+            points: self.points,
+            character: self.character,
+            booster: self.booster,
+            arsenal: self.arsenal,
+            queue: self.queue,
+        }
+    }
+
     fn can_dequeue(&self) -> bool {
         self.arsenal.len() < self.game_config.max_arsenal_items as usize || self.queue.exit_vacant()
     }
@@ -37,30 +93,35 @@ impl DequeueChoicelessPlayer {
 
 impl Choose<DequeueChoice> for DequeueChoicelessPlayer {
     fn choices(&self) -> Vec<DequeueChoice> {
-        if self.can_dequeue() {
-            let mut items: Vec<DequeueChoice> = self
-                .queue
-                .pool()
-                .iter()
-                .map(|item| DequeueChoice::DrainAndExit(*item))
-                .collect();
-            items.push(DequeueChoice::JustExit);
-            items.push(DequeueChoice::Decline);
-            items
-        } else {
-            vec![DequeueChoice::Decline]
+        self.legal_dequeue_choices().collect()
+    }
+}
+
+impl ExplainIllegal<DequeueChoice> for DequeueChoicelessPlayer {
+    fn explain_illegal(&self, dequeue_choice: &DequeueChoice) -> Option<IllegalChoice> {
+        match dequeue_choice {
+            DequeueChoice::Decline => None,
+            _ if !self.can_dequeue() => Some(IllegalChoice::ExitOccupiedAndArsenalFull),
+            DequeueChoice::JustExit => None,
+            DequeueChoice::DrainAndExit(item) => {
+                if self.queue.pool().contains(*item) {
+                    None
+                } else {
+                    Some(IllegalChoice::NotInPool(*item))
+                }
+            }
         }
     }
 }
 
-impl Into<transparent::DequeueingPlayer> for DequeueChoicelessPlayer {
-    fn into(self) -> transparent::DequeueingPlayer {
+impl From<DequeueChoicelessPlayer> for transparent::DequeueingPlayer {
+    fn from(player: DequeueChoicelessPlayer) -> transparent::DequeueingPlayer {
         transparent::DequeueingPlayer {
-            points: self.points,
-            character: self.character,
-            booster: self.booster,
-            arsenal: self.arsenal,
-            queue: self.queue.into(),
+            points: player.points,
+            character: player.character,
+            booster: player.booster,
+            arsenal: player.arsenal.to_vec(),
+            queue: player.queue.into(),
         }
     }
 }
@@ -68,6 +129,7 @@ impl Into<transparent::DequeueingPlayer> for DequeueChoicelessPlayer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::choices::ArsenalItem;
 
     fn shadow() -> DequeueChoicelessPlayer {
         use crate::{outcomes::CharacterHeadstart, players::CharacterlessPlayer};
@@ -114,7 +176,7 @@ mod tests {
         let mut shadow = shadow();
         let drain_mirror = DequeueChoice::DrainAndExit(ArsenalItem::Mirror);
         shadow.queue.dequeue(drain_mirror).unwrap();
-        shadow.arsenal = vec![];
+        shadow.arsenal = ArsenalSet::new();
         assert!(shadow.can_dequeue());
         assert_eq!(
             vec![DequeueChoice::JustExit, DequeueChoice::Decline],
@@ -139,7 +201,7 @@ mod tests {
             .dequeue(DequeueChoice::DrainAndExit(ArsenalItem::Mirror))
             .unwrap();
         assert!(!shadow.queue.exit_vacant());
-        shadow.arsenal = vec![ArsenalItem::Move(Move::Nunchucks)];
+        shadow.arsenal = [ArsenalItem::Move(Move::Nunchucks)][..].into();
         assert!(shadow.can_dequeue());
     }
 
@@ -153,13 +215,47 @@ mod tests {
             .dequeue(DequeueChoice::DrainAndExit(ArsenalItem::Mirror))
             .unwrap();
         assert!(!shadow.queue.exit_vacant());
-        shadow.arsenal = vec![
+        shadow.arsenal = [
             ArsenalItem::Move(Move::Nunchucks),
             ArsenalItem::Move(Move::ShadowFireball),
-        ];
+        ][..]
+            .into();
         assert!(!shadow.can_dequeue());
     }
 
+    #[test]
+    fn explain_illegal_reports_full_arsenal_and_missing_drainee() {
+        use crate::choices::Move;
+
+        let mut shadow = shadow();
+        let drain_kick = DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::Kick));
+        assert_eq!(
+            Some(IllegalChoice::NotInPool(ArsenalItem::Move(Move::Kick))),
+            shadow.explain_illegal(&drain_kick)
+        );
+
+        shadow
+            .queue
+            .dequeue(DequeueChoice::DrainAndExit(ArsenalItem::Mirror))
+            .unwrap();
+        assert_eq!(
+            Some(IllegalChoice::ExitOccupiedAndArsenalFull),
+            shadow.explain_illegal(&DequeueChoice::JustExit)
+        );
+        assert_eq!(None, shadow.explain_illegal(&DequeueChoice::Decline));
+    }
+
+    #[test]
+    fn into_finished_works() {
+        let shadow = shadow();
+        let finished = shadow.clone().into_finished();
+
+        assert_eq!(shadow.points, finished.points);
+        assert_eq!(shadow.booster, finished.booster);
+        assert_eq!(shadow.arsenal, finished.arsenal);
+        assert_eq!(shadow.queue, finished.queue);
+    }
+
     #[test]
     fn into_transparent_works() {
         let original = shadow();
@@ -168,7 +264,7 @@ mod tests {
         assert_eq!(original.points, transparent.points);
         assert_eq!(original.character, transparent.character);
         assert_eq!(original.booster, transparent.booster);
-        assert_eq!(original.arsenal, transparent.arsenal);
+        assert_eq!(original.arsenal.to_vec(), transparent.arsenal);
         assert_eq!(
             Into::<transparent::Queue>::into(original.queue),
             transparent.queue
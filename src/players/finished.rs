@@ -1,4 +1,4 @@
-use crate::choices::{ArsenalItem, Booster, Character};
+use crate::choices::{ArsenalSet, Booster, Character};
 use crate::counters::Queue;
 use crate::game::Config;
 use crate::scoreboard::transparent;
@@ -9,18 +9,58 @@ pub struct FinishedPlayer {
     pub(crate) points: u8,
     pub(super) character: Character,
     pub(super) booster: Booster,
-    pub(super) arsenal: Vec<ArsenalItem>,
+    pub(super) arsenal: ArsenalSet,
     pub(super) queue: Queue,
 }
 
-impl Into<transparent::FinishedPlayer> for FinishedPlayer {
-    fn into(self) -> transparent::FinishedPlayer {
+impl FinishedPlayer {
+    pub(crate) fn from_parts(
+        game_config: Config,
+        points: u8,
+        character: Character,
+        booster: Booster,
+        arsenal: ArsenalSet,
+        queue: Queue,
+    ) -> Self {
+        Self {
+            game_config,
+            points,
+            character,
+            booster,
+            arsenal,
+            queue,
+        }
+    }
+
+    pub fn points(&self) -> u8 {
+        self.points
+    }
+
+    pub fn character(&self) -> Character {
+        self.character
+    }
+
+    pub fn booster(&self) -> Booster {
+        self.booster
+    }
+
+    pub fn arsenal(&self) -> ArsenalSet {
+        self.arsenal
+    }
+
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+}
+
+impl From<FinishedPlayer> for transparent::FinishedPlayer {
+    fn from(player: FinishedPlayer) -> transparent::FinishedPlayer {
         transparent::FinishedPlayer {
-            points: self.points,
-            character: self.character,
-            booster: self.booster,
-            arsenal: self.arsenal,
-            queue: self.queue.into(),
+            points: player.points,
+            character: player.character,
+            booster: player.booster,
+            arsenal: player.arsenal.to_vec(),
+            queue: player.queue.into(),
         }
     }
 }
@@ -30,7 +70,7 @@ mod tests {
     use super::*;
 
     fn finished_ninja() -> FinishedPlayer {
-        use crate::choices::{DequeueChoice, Move};
+        use crate::choices::{ArsenalItem, DequeueChoice, Move};
 
         let mut queue = Queue::new();
         queue.enqueue(Some(ArsenalItem::Move(Move::NinjaSword)));
@@ -45,10 +85,11 @@ mod tests {
             game_config: Config::default(),
             character: Character::Ninja,
             booster: Booster::Shadow,
-            arsenal: vec![
+            arsenal: [
                 ArsenalItem::Move(Move::ShadowFireball),
                 ArsenalItem::Move(Move::Nunchucks),
-            ],
+            ][..]
+                .into(),
             queue,
         }
     }
@@ -61,7 +102,7 @@ mod tests {
         assert_eq!(original.points, transparent.points);
         assert_eq!(original.character, transparent.character);
         assert_eq!(original.booster, transparent.booster);
-        assert_eq!(original.arsenal, transparent.arsenal);
+        assert_eq!(original.arsenal.to_vec(), transparent.arsenal);
         assert_eq!(
             Into::<transparent::Queue>::into(original.queue),
             transparent.queue
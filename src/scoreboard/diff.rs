@@ -0,0 +1,310 @@
+use super::{CharacterStreak, Queue, Scoreboard};
+use crate::choices::{ArsenalItem, Booster, Character};
+use crate::game::PhaseKind;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScoreboardDiff {
+    pub phase: Option<(PhaseKind, PhaseKind)>,
+    pub players: Vec<PlayerDiff>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PlayerDiff {
+    pub points: i16,
+    pub character: Option<Character>,
+    pub booster: Option<Booster>,
+    pub streak: Option<CharacterStreak>,
+    pub arsenal_added: Vec<ArsenalItem>,
+    pub arsenal_removed: Vec<ArsenalItem>,
+    pub queue: QueueDiff,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct QueueDiff {
+    pub entrance: Option<Option<ArsenalItem>>,
+    pub pool_added: Vec<ArsenalItem>,
+    pub pool_removed: Vec<ArsenalItem>,
+    pub exit: Option<Option<ArsenalItem>>,
+}
+
+impl ScoreboardDiff {
+    pub(super) fn between(old: &Scoreboard, new: &Scoreboard) -> ScoreboardDiff {
+        let old_kind = old.phase_kind();
+        let new_kind = new.phase_kind();
+        let phase = if old_kind == new_kind {
+            None
+        } else {
+            Some((old_kind, new_kind))
+        };
+
+        let old_players = PlayerSnapshot::all(old);
+        let new_players = PlayerSnapshot::all(new);
+        let player_count = old_players.len().max(new_players.len());
+        let players = (0..player_count)
+            .map(|i| {
+                let old = old_players.get(i).cloned().unwrap_or_default();
+                let new = new_players.get(i).cloned().unwrap_or_default();
+                PlayerDiff::between(&old, &new)
+            })
+            .collect();
+
+        ScoreboardDiff { phase, players }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.phase.is_none() && self.players.iter().all(PlayerDiff::is_empty)
+    }
+}
+
+impl PlayerDiff {
+    fn between(old: &PlayerSnapshot, new: &PlayerSnapshot) -> PlayerDiff {
+        PlayerDiff {
+            points: i16::from(new.points) - i16::from(old.points),
+            character: changed(old.character, new.character).flatten(),
+            booster: changed(old.booster, new.booster).flatten(),
+            streak: match (&old.streak, &new.streak) {
+                (Some(old), Some(new)) if old != new => Some(new.clone()),
+                _ => None,
+            },
+            arsenal_added: difference(new.arsenal, old.arsenal),
+            arsenal_removed: difference(old.arsenal, new.arsenal),
+            queue: QueueDiff::between(old.queue, new.queue),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &PlayerDiff::default()
+    }
+}
+
+impl QueueDiff {
+    fn between(old: Option<&Queue>, new: Option<&Queue>) -> QueueDiff {
+        let empty: &[ArsenalItem] = &[];
+        let old_pool = old.map(|q| &q.pool[..]).unwrap_or(empty);
+        let new_pool = new.map(|q| &q.pool[..]).unwrap_or(empty);
+
+        QueueDiff {
+            entrance: changed(old.and_then(|q| q.entrance), new.and_then(|q| q.entrance)),
+            pool_added: difference(new_pool, old_pool),
+            pool_removed: difference(old_pool, new_pool),
+            exit: changed(old.and_then(|q| q.exit), new.and_then(|q| q.exit)),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct PlayerSnapshot<'a> {
+    points: u8,
+    character: Option<Character>,
+    booster: Option<Booster>,
+    streak: Option<CharacterStreak>,
+    arsenal: &'a [ArsenalItem],
+    queue: Option<&'a Queue>,
+}
+
+impl<'a> PlayerSnapshot<'a> {
+    fn all(scoreboard: &'a Scoreboard) -> Vec<PlayerSnapshot<'a>> {
+        match scoreboard {
+            Scoreboard::Characterless(players) => players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    streak: p.streak.clone(),
+                    ..PlayerSnapshot::default()
+                })
+                .collect(),
+            Scoreboard::Boosterless(players) => players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    points: p.points,
+                    character: Some(p.character),
+                    ..PlayerSnapshot::default()
+                })
+                .collect(),
+            Scoreboard::Dequeueing(players) => players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    points: p.points,
+                    character: Some(p.character),
+                    booster: Some(p.booster),
+                    streak: None,
+                    arsenal: &p.arsenal,
+                    queue: Some(&p.queue),
+                })
+                .collect(),
+            Scoreboard::Actionless(players) => players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    points: p.points,
+                    character: Some(p.character),
+                    booster: Some(p.booster),
+                    streak: None,
+                    arsenal: &p.arsenal,
+                    queue: Some(&p.queue),
+                })
+                .collect(),
+            Scoreboard::Final(players) => players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    points: p.points,
+                    character: Some(p.character),
+                    booster: Some(p.booster),
+                    streak: None,
+                    arsenal: &p.arsenal,
+                    queue: Some(&p.queue),
+                })
+                .collect(),
+            Scoreboard::Drawn(scoreboard) => PlayerSnapshot::all(scoreboard),
+        }
+    }
+}
+
+fn changed<T: PartialEq>(old: T, new: T) -> Option<T> {
+    if old == new {
+        None
+    } else {
+        Some(new)
+    }
+}
+
+fn difference(items: &[ArsenalItem], removed: &[ArsenalItem]) -> Vec<ArsenalItem> {
+    let mut remaining = removed.to_vec();
+    let mut difference = vec![];
+    for item in items {
+        if let Some(position) = remaining.iter().position(|r| r == item) {
+            remaining.swap_remove(position);
+        } else {
+            difference.push(*item);
+        }
+    }
+    difference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{Action, BatchChoice, DequeueChoice, Move};
+    use crate::game::BatchChoiceGame;
+
+    fn ninja_samurai_game() -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game
+    }
+
+    #[test]
+    fn diff_of_identical_scoreboards_is_empty() {
+        let game = ninja_samurai_game();
+        assert!(game.scoreboard().diff(&game.scoreboard()).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_phase_change_and_headstart() {
+        let before = BatchChoiceGame::default().scoreboard();
+        let after = ninja_samurai_game().scoreboard();
+        let diff = before.diff(&after);
+
+        assert_eq!(Some((PhaseKind::Character, PhaseKind::Booster)), diff.phase);
+        assert_eq!(1, diff.players[0].points);
+        assert_eq!(Some(Character::Ninja), diff.players[0].character);
+        assert_eq!(0, diff.players[1].points);
+        assert_eq!(Some(Character::Samurai), diff.players[1].character);
+    }
+
+    #[test]
+    fn diff_reports_streak_change() {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Ninja,
+        ]))
+        .unwrap();
+        let before = game.scoreboard();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Samurai,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        let diff = before.diff(&game.scoreboard());
+
+        assert_eq!(None, diff.phase);
+        assert_eq!(
+            Some(CharacterStreak {
+                character: Character::Samurai,
+                times: 1,
+            }),
+            diff.players[0].streak
+        );
+    }
+
+    #[test]
+    fn diff_ignores_streak_appearing_or_disappearing() {
+        let mut game = BatchChoiceGame::default();
+        let before = game.scoreboard();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Ninja,
+        ]))
+        .unwrap();
+        let rechosen = game.scoreboard();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+
+        assert_eq!(None, before.diff(&rechosen).players[0].streak);
+        let diff = rechosen.diff(&game.scoreboard());
+        assert_eq!(Some((PhaseKind::Character, PhaseKind::Booster)), diff.phase);
+        assert_eq!(None, diff.players[0].streak);
+    }
+
+    #[test]
+    fn diff_reports_arsenal_and_queue_movements() {
+        let mut game = ninja_samurai_game();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        let before = game.scoreboard();
+        game.choose(BatchChoice::Actions(vec![
+            Action::Move(Move::ShadowFireball),
+            Action::Move(Move::Lightning),
+        ]))
+        .unwrap();
+        let diff = before.diff(&game.scoreboard());
+        let ninja = &diff.players[0];
+
+        assert_eq!(Some((PhaseKind::Action, PhaseKind::Dequeue)), diff.phase);
+        assert_eq!(1, ninja.points);
+        assert_eq!(
+            vec![ArsenalItem::Move(Move::ShadowFireball)],
+            ninja.arsenal_removed
+        );
+        assert!(ninja.arsenal_added.is_empty());
+        assert_eq!(
+            Some(Some(ArsenalItem::Move(Move::ShadowFireball))),
+            ninja.queue.entrance
+        );
+        assert_eq!(None, ninja.queue.exit);
+        assert!(ninja.queue.pool_added.is_empty());
+    }
+
+    #[test]
+    fn difference_respects_multiplicity() {
+        let kick = ArsenalItem::Move(Move::Kick);
+        assert_eq!(
+            vec![kick],
+            difference(
+                &[kick, kick, ArsenalItem::Mirror],
+                &[ArsenalItem::Mirror, kick]
+            )
+        );
+    }
+}
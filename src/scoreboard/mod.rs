@@ -1,5 +1,12 @@
 pub(crate) mod transparent;
 pub use transparent::*;
+mod diff;
+pub use diff::{PlayerDiff, QueueDiff, ScoreboardDiff};
+mod queue_ref;
+pub use crate::counters::{Pool, PoolIter};
+pub use queue_ref::QueueRef;
+
+use crate::game::PhaseKind;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Scoreboard {
@@ -8,9 +15,24 @@ pub enum Scoreboard {
     Dequeueing(Vec<DequeueingPlayer>),
     Actionless(Vec<ActionlessPlayer>),
     Final(Vec<FinishedPlayer>),
+    Drawn(Box<Scoreboard>),
 }
 
 impl Scoreboard {
+    pub fn phase_kind(&self) -> PhaseKind {
+        match self {
+            Scoreboard::Characterless(_) => PhaseKind::Character,
+            Scoreboard::Boosterless(_) => PhaseKind::Booster,
+            Scoreboard::Dequeueing(_) => PhaseKind::Dequeue,
+            Scoreboard::Actionless(_) => PhaseKind::Action,
+            Scoreboard::Final(_) | Scoreboard::Drawn(_) => PhaseKind::Final,
+        }
+    }
+
+    pub fn diff(&self, other: &Scoreboard) -> ScoreboardDiff {
+        ScoreboardDiff::between(self, other)
+    }
+
     pub fn characterless(self) -> Option<Vec<CharacterlessPlayer>> {
         if let Scoreboard::Characterless(players) = self {
             Some(players)
@@ -50,12 +72,29 @@ impl Scoreboard {
             None
         }
     }
+
+    pub fn drawn(self) -> Option<Scoreboard> {
+        if let Scoreboard::Drawn(scoreboard) = self {
+            Some(*scoreboard)
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn phase_kind_works() {
+        assert_eq!(
+            PhaseKind::Character,
+            Scoreboard::Characterless(vec![]).phase_kind()
+        );
+        assert_eq!(PhaseKind::Final, Scoreboard::Final(vec![]).phase_kind());
+    }
+
     #[test]
     fn characterless_returns_some_if_variant_is_characterless() {
         let scoreboard = Scoreboard::Characterless(vec![]);
@@ -115,4 +154,17 @@ mod tests {
         let scoreboard = Scoreboard::Dequeueing(vec![]);
         assert!(scoreboard.final_().is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn drawn_returns_inner_scoreboard_if_variant_is_drawn() {
+        let scoreboard = Scoreboard::Drawn(Box::new(Scoreboard::Characterless(vec![])));
+        assert_eq!(PhaseKind::Final, scoreboard.phase_kind());
+        assert_eq!(Some(Scoreboard::Characterless(vec![])), scoreboard.drawn());
+    }
+
+    #[test]
+    fn drawn_returns_none_if_variant_is_not_drawn() {
+        let scoreboard = Scoreboard::Final(vec![]);
+        assert!(scoreboard.drawn().is_none());
+    }
+}
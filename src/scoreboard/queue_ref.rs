@@ -0,0 +1,53 @@
+use crate::choices::ArsenalItem;
+use crate::counters::{Pool, Queue};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueueRef<'a>(&'a Queue);
+
+impl<'a> QueueRef<'a> {
+    pub(crate) fn new(queue: &'a Queue) -> Self {
+        QueueRef(queue)
+    }
+
+    pub fn entrance(&self) -> Option<ArsenalItem> {
+        self.0.entrance()
+    }
+
+    pub fn pool(&self) -> &'a Pool {
+        self.0.pool()
+    }
+
+    pub fn exit(&self) -> Option<ArsenalItem> {
+        self.0.exit()
+    }
+}
+
+impl<'a> From<QueueRef<'a>> for super::Queue {
+    fn from(queue: QueueRef<'a>) -> super::Queue {
+        super::Queue {
+            entrance: queue.entrance(),
+            pool: queue.pool().to_vec(),
+            exit: queue.exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{DequeueChoice, Move};
+
+    #[test]
+    fn queue_ref_matches_transparent_queue() {
+        let mut queue = Queue::new();
+        queue.enqueue(Some(ArsenalItem::Move(Move::Kick)));
+        queue.enqueue(Some(ArsenalItem::Move(Move::Nunchucks)));
+        queue
+            .dequeue(DequeueChoice::DrainAndExit(ArsenalItem::Mirror))
+            .unwrap();
+
+        let borrowed: super::super::Queue = QueueRef::new(&queue).into();
+        let owned: super::super::Queue = queue.into();
+        assert_eq!(owned, borrowed);
+    }
+}
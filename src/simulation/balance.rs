@@ -0,0 +1,401 @@
+use super::parallel::rate;
+use crate::agents::{Agent, Driver};
+use crate::choices::{Action, BatchChoice, Booster, Character, Move};
+use crate::game::{BatchChoiceGame, Config};
+use crate::outcomes::Outcome;
+
+use std::fmt::{self, Display, Formatter};
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Loadout {
+    pub character: Character,
+    pub booster: Booster,
+}
+
+impl Loadout {
+    pub fn all() -> Vec<Loadout> {
+        Character::all()
+            .into_iter()
+            .flat_map(|character| {
+                character
+                    .boosters()
+                    .into_iter()
+                    .map(move |booster| Loadout { character, booster })
+            })
+            .collect()
+    }
+}
+
+impl Display for Loadout {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.character, self.booster)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matchup {
+    pub first: Loadout,
+    pub second: Loadout,
+    pub games: u64,
+    pub first_wins: u64,
+    pub second_wins: u64,
+    pub draws: u64,
+    pub total_margin: i64,
+}
+
+impl Matchup {
+    fn new(first: Loadout, second: Loadout) -> Self {
+        Self {
+            first,
+            second,
+            games: 0,
+            first_wins: 0,
+            second_wins: 0,
+            draws: 0,
+            total_margin: 0,
+        }
+    }
+
+    fn merge(&mut self, other: &Matchup) {
+        self.games += other.games;
+        self.first_wins += other.first_wins;
+        self.second_wins += other.second_wins;
+        self.draws += other.draws;
+        self.total_margin += other.total_margin;
+    }
+
+    pub fn first_win_rate(&self) -> f64 {
+        rate(self.first_wins, self.games)
+    }
+
+    pub fn second_win_rate(&self) -> f64 {
+        rate(self.second_wins, self.games)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        rate(self.draws, self.games)
+    }
+
+    pub fn average_margin(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.total_margin as f64 / self.games as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchupDelta {
+    pub first: Loadout,
+    pub second: Loadout,
+    pub first_win_rate: f64,
+    pub average_margin: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BalanceReport {
+    pub matchups: Vec<Matchup>,
+    pub move_usage: Vec<u64>,
+}
+
+impl BalanceReport {
+    fn empty() -> Self {
+        let loadouts = Loadout::all();
+        let mut matchups = vec![];
+        for (i, &first) in loadouts.iter().enumerate() {
+            for &second in &loadouts[i + 1..] {
+                if first.character != second.character {
+                    matchups.push(Matchup::new(first, second));
+                }
+            }
+        }
+
+        Self {
+            matchups,
+            move_usage: vec![0; Move::all().len()],
+        }
+    }
+
+    fn merge(&mut self, other: &BalanceReport) {
+        for (matchup, other) in self.matchups.iter_mut().zip(&other.matchups) {
+            matchup.merge(other);
+        }
+        for (usage, other) in self.move_usage.iter_mut().zip(&other.move_usage) {
+            *usage += other;
+        }
+    }
+
+    pub fn matchup(&self, first: Loadout, second: Loadout) -> Option<&Matchup> {
+        self.matchups
+            .iter()
+            .find(|m| m.first == first && m.second == second)
+    }
+
+    pub fn loadout_win_rates(&self) -> Vec<(Loadout, f64)> {
+        Loadout::all()
+            .into_iter()
+            .map(|loadout| {
+                let mut wins = 0;
+                let mut games = 0;
+                for matchup in &self.matchups {
+                    if matchup.first == loadout {
+                        wins += matchup.first_wins;
+                        games += matchup.games;
+                    } else if matchup.second == loadout {
+                        wins += matchup.second_wins;
+                        games += matchup.games;
+                    }
+                }
+                (loadout, rate(wins, games))
+            })
+            .collect()
+    }
+
+    pub fn move_usage_rates(&self) -> Vec<(Move, f64)> {
+        let total = self.move_usage.iter().sum();
+        Move::all()
+            .into_iter()
+            .zip(&self.move_usage)
+            .map(|(m, &usage)| (m, rate(usage, total)))
+            .collect()
+    }
+
+    pub fn compare(&self, baseline: &BalanceReport) -> Vec<MatchupDelta> {
+        self.matchups
+            .iter()
+            .filter_map(|matchup| {
+                let before = baseline.matchup(matchup.first, matchup.second)?;
+                Some(MatchupDelta {
+                    first: matchup.first,
+                    second: matchup.second,
+                    first_win_rate: matchup.first_win_rate() - before.first_win_rate(),
+                    average_margin: matchup.average_margin() - before.average_margin(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for BalanceReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:<24} {:>6} {:>7} {:>7} {:>7} {:>7}",
+            "first", "second", "games", "first%", "second%", "draw%", "margin"
+        )?;
+        for m in &self.matchups {
+            writeln!(
+                f,
+                "{:<24} {:<24} {:>6} {:>7.1} {:>7.1} {:>7.1} {:>7.2}",
+                m.first.to_string(),
+                m.second.to_string(),
+                m.games,
+                100.0 * m.first_win_rate(),
+                100.0 * m.second_win_rate(),
+                100.0 * m.draw_rate(),
+                m.average_margin()
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{:<28} {:>7}", "move", "usage%")?;
+        for (m, usage) in self.move_usage_rates() {
+            writeln!(f, "{:<28} {:>7.1}", m.to_string(), 100.0 * usage)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn balance_report<A, F>(
+    config: &Config,
+    agents: F,
+    games_per_matchup: u64,
+    seed: u64,
+    threads: usize,
+) -> Result<BalanceReport, ()>
+where
+    A: Agent,
+    F: Fn(u64) -> Vec<A> + Sync,
+{
+    if config.player_count != 2 || !config.has_stalemate_limit() {
+        return Err(());
+    }
+
+    let threads = threads.max(1) as u64;
+    let matchup_count = BalanceReport::empty().matchups.len() as u64;
+    let total_games = matchup_count * games_per_matchup;
+    let agents = &agents;
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|worker| {
+                scope.spawn(move || {
+                    let mut report = BalanceReport::empty();
+                    let mut game = worker;
+                    while game < total_games {
+                        let matchup = (game / games_per_matchup) as usize;
+                        let swapped = game % games_per_matchup % 2 == 1;
+                        play(
+                            config,
+                            agents(seed.wrapping_add(game)),
+                            &mut report,
+                            matchup,
+                            swapped,
+                        )?;
+                        game += threads;
+                    }
+                    Ok(report)
+                })
+            })
+            .collect();
+
+        let mut report = BalanceReport::empty();
+        for worker in workers {
+            report.merge(&worker.join().map_err(|_| ())??);
+        }
+        Ok(report)
+    })
+}
+
+fn play<A: Agent>(
+    config: &Config,
+    agents: Vec<A>,
+    report: &mut BalanceReport,
+    matchup_index: usize,
+    swapped: bool,
+) -> Result<(), ()> {
+    let matchup = &report.matchups[matchup_index];
+    let (first, second) = if swapped {
+        (matchup.second, matchup.first)
+    } else {
+        (matchup.first, matchup.second)
+    };
+
+    let mut game = BatchChoiceGame::new(config.clone());
+    game.choose(BatchChoice::Characters(vec![
+        first.character,
+        second.character,
+    ]))?;
+    game.choose(BatchChoice::Boosters(vec![first.booster, second.booster]))?;
+    let mut driver = Driver::from_game(game, agents)?;
+
+    for outcome in driver.run()? {
+        if let Outcome::ActionPhaseDone(apds) | Outcome::GameOver(apds) | Outcome::Draw(apds) =
+            outcome
+        {
+            for apd in apds {
+                if let Action::Move(m) | Action::Mirror(m) = apd.0 {
+                    report.move_usage[usize::from(m.to_index())] += 1;
+                }
+            }
+        }
+    }
+
+    let game = driver.game();
+    let points = |i| i64::from(game.points(i).unwrap_or(0));
+    let (first_index, second_index) = if swapped { (1, 0) } else { (0, 1) };
+    let matchup = &mut report.matchups[matchup_index];
+    matchup.games += 1;
+    matchup.total_margin += points(first_index) - points(second_index);
+    match game.winner_index() {
+        Some(winner) if winner == first_index => matchup.first_wins += 1,
+        Some(_) => matchup.second_wins += 1,
+        None => matchup.draws += 1,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::RandomAgent;
+    use crate::choices::MoveOutcomes;
+
+    fn random_agents(seed: u64) -> Vec<RandomAgent> {
+        vec![RandomAgent::new(seed), RandomAgent::new(seed ^ 0xFF)]
+    }
+
+    fn limited_config() -> Config {
+        Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn loadouts_cover_every_character_booster_pair() {
+        assert_eq!(12, Loadout::all().len());
+        assert_eq!(54, BalanceReport::empty().matchups.len());
+    }
+
+    #[test]
+    fn report_plays_every_matchup() {
+        let report = balance_report(&limited_config(), random_agents, 2, 0, 3).unwrap();
+        for matchup in &report.matchups {
+            assert_eq!(2, matchup.games);
+            assert_eq!(2, matchup.first_wins + matchup.second_wins + matchup.draws);
+        }
+        assert!(report.move_usage.iter().sum::<u64>() > 0);
+    }
+
+    #[test]
+    fn report_does_not_depend_on_thread_count() {
+        let one = balance_report(&limited_config(), random_agents, 2, 1, 1).unwrap();
+        let many = balance_report(&limited_config(), random_agents, 2, 1, 4).unwrap();
+        assert_eq!(one, many);
+    }
+
+    #[test]
+    fn comparing_report_with_itself_shows_no_change() {
+        let report = balance_report(&limited_config(), random_agents, 1, 0, 2).unwrap();
+        for delta in report.compare(&report) {
+            assert_eq!(0.0, delta.first_win_rate);
+            assert_eq!(0.0, delta.average_margin);
+        }
+    }
+
+    #[test]
+    fn report_uses_config_move_outcomes() {
+        let mut move_outcomes = MoveOutcomes::default();
+        for m in Move::all() {
+            move_outcomes.set(Move::Kick, m, 1);
+            move_outcomes.set(m, Move::Kick, 0);
+        }
+        let variant = Config {
+            move_outcomes,
+            ..limited_config()
+        };
+        let baseline = balance_report(&limited_config(), random_agents, 2, 0, 2).unwrap();
+        let report = balance_report(&variant, random_agents, 2, 0, 2).unwrap();
+
+        assert!(report
+            .compare(&baseline)
+            .iter()
+            .any(|delta| delta.average_margin != 0.0));
+    }
+
+    #[test]
+    fn table_has_row_per_matchup_and_move() {
+        let report = balance_report(&limited_config(), random_agents, 1, 0, 2).unwrap();
+        let table = report.to_string();
+        assert_eq!(1 + 54 + 1 + 1 + 29, table.lines().count());
+        assert!(table.contains("Ninja/Shadow"));
+    }
+
+    #[test]
+    fn report_requires_stalemate_limit() {
+        assert!(balance_report(&Config::default(), random_agents, 1, 0, 1).is_err());
+    }
+
+    #[test]
+    fn report_requires_two_players() {
+        let config = Config {
+            player_count: 3,
+            ..limited_config()
+        };
+        assert!(balance_report(&config, |seed| vec![RandomAgent::new(seed); 3], 1, 0, 1).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+mod balance;
+pub use balance::{balance_report, BalanceReport, Loadout, Matchup, MatchupDelta};
+mod parallel;
+pub use parallel::{parallel_simulate, SimulationSummary};
+mod self_play;
+pub use self_play::{self_play, Record, RecordFormat, SelfPlayConfig, SelfPlaySummary};
+
+use crate::choices::BatchChoice;
+use crate::game::{BatchChoiceGame, Config};
+use crate::outcomes::Outcome;
+use crate::random::Prng;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Replay {
+    pub config: Config,
+    pub seed: u64,
+    pub choices: Vec<BatchChoice>,
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Replay {
+    pub fn game(&self) -> Result<BatchChoiceGame, ()> {
+        let mut game = BatchChoiceGame::new(self.config.clone());
+        for choice in &self.choices {
+            game.choose(choice.clone())?;
+        }
+        Ok(game)
+    }
+}
+
+pub fn simulate(config: Config, seed: u64) -> Result<Replay, ()> {
+    if !config.has_stalemate_limit() {
+        return Err(());
+    }
+
+    let mut rng = Prng::new(seed);
+    let mut game = BatchChoiceGame::new(config.clone());
+    let mut choices = vec![];
+    let mut outcomes = vec![];
+
+    while let Some(choice) = game.choices().random(&mut rng) {
+        let outcome = game
+            .choose(choice.clone())
+            .expect("random choices are always legal");
+        choices.push(choice);
+        outcomes.push(outcome);
+    }
+
+    Ok(Replay {
+        config,
+        seed,
+        choices,
+        outcomes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{Action, ArsenalItem, Booster, Character, DequeueChoice, Move};
+
+    fn limited_config() -> Config {
+        Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn simulate_plays_game_to_completion() {
+        let replay = simulate(limited_config(), 0).unwrap();
+        assert!(replay.game().unwrap().is_over());
+        assert_eq!(replay.choices.len(), replay.outcomes.len());
+    }
+
+    #[test]
+    fn simulate_requires_stalemate_limit() {
+        assert!(simulate(Config::default(), 0).is_err());
+    }
+
+    #[test]
+    fn simulate_is_reproducible() {
+        assert_eq!(
+            simulate(limited_config(), 123),
+            simulate(limited_config(), 123)
+        );
+    }
+
+    #[test]
+    fn different_seeds_play_different_games() {
+        let games: Vec<Replay> = (0..5)
+            .map(|seed| simulate(limited_config(), seed).unwrap())
+            .collect();
+        assert!(games.iter().any(|g| g.choices != games[0].choices));
+    }
+
+    #[test]
+    fn simulate_matches_golden_replay() {
+        let replay = simulate(limited_config(), 7).unwrap();
+        let expected = vec![
+            BatchChoice::Characters(vec![Character::Clown, Character::Ninja]),
+            BatchChoice::Boosters(vec![Booster::Backwards, Booster::Shadow]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::JustExit,
+                DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            ]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::BackwardsMoustachio),
+                Action::Move(Move::Nunchucks),
+            ]),
+            BatchChoice::DequeueChoices(vec![DequeueChoice::Decline, DequeueChoice::Decline]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::NoseOfTheTaunted),
+                Action::Move(Move::Kick),
+            ]),
+            BatchChoice::DequeueChoices(vec![DequeueChoice::JustExit, DequeueChoice::Decline]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::JugglingKnives),
+                Action::Move(Move::NinjaSword),
+            ]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+                DequeueChoice::Decline,
+            ]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::Nose),
+                Action::Move(Move::ShadowFireball),
+            ]),
+            BatchChoice::DequeueChoices(vec![DequeueChoice::JustExit, DequeueChoice::Decline]),
+            BatchChoice::Actions(vec![
+                Action::Mirror(Move::JugglingKnives),
+                Action::Move(Move::ShadowSlip),
+            ]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::BackwardsMoustachio)),
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::ShadowFireball)),
+            ]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::AcidSpray),
+                Action::Mirror(Move::Kick),
+            ]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::JugglingKnives)),
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::Kick)),
+            ]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::BackwardsMoustachio),
+                Action::Move(Move::ShadowFireball),
+            ]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+                DequeueChoice::JustExit,
+            ]),
+            BatchChoice::Actions(vec![
+                Action::Move(Move::JugglingKnives),
+                Action::Move(Move::Kick),
+            ]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::JustExit,
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::ShadowSlip)),
+            ]),
+            BatchChoice::Actions(vec![
+                Action::Mirror(Move::BackwardsMoustachio),
+                Action::Concede,
+            ]),
+            BatchChoice::DequeueChoices(vec![
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::Nose)),
+                DequeueChoice::DrainAndExit(ArsenalItem::Move(Move::NinjaSword)),
+            ]),
+            BatchChoice::Actions(vec![Action::Concede, Action::Move(Move::ShadowSlip)]),
+        ];
+        assert_eq!(expected, replay.choices);
+        assert_eq!(Some(1), replay.game().unwrap().winner_index());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::agents::{Agent, Driver};
+use crate::choices::{Booster, Character};
+use crate::game::Config;
+use crate::outcomes::Outcome;
+
+use std::thread;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SimulationSummary {
+    pub games: u64,
+    pub wins: Vec<u64>,
+    pub draws: u64,
+    pub total_choices: u64,
+    pub total_rounds: u64,
+    pub character_picks: Vec<u64>,
+    pub booster_picks: Vec<u64>,
+}
+
+impl SimulationSummary {
+    fn new(player_count: usize) -> Self {
+        Self {
+            wins: vec![0; player_count],
+            character_picks: vec![0; Character::all().len()],
+            booster_picks: vec![0; Booster::all().len()],
+            ..Self::default()
+        }
+    }
+
+    fn merge(&mut self, other: &SimulationSummary) {
+        self.games += other.games;
+        self.draws += other.draws;
+        self.total_choices += other.total_choices;
+        self.total_rounds += other.total_rounds;
+        add(&mut self.wins, &other.wins);
+        add(&mut self.character_picks, &other.character_picks);
+        add(&mut self.booster_picks, &other.booster_picks);
+    }
+
+    pub fn win_rates(&self) -> Vec<f64> {
+        self.wins.iter().map(|&w| rate(w, self.games)).collect()
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        rate(self.draws, self.games)
+    }
+
+    pub fn average_choices(&self) -> f64 {
+        rate(self.total_choices, self.games)
+    }
+
+    pub fn average_rounds(&self) -> f64 {
+        rate(self.total_rounds, self.games)
+    }
+
+    pub fn character_pick_rates(&self) -> Vec<(Character, f64)> {
+        let total = self.character_picks.iter().sum();
+        Character::all()
+            .into_iter()
+            .zip(&self.character_picks)
+            .map(|(character, &picks)| (character, rate(picks, total)))
+            .collect()
+    }
+
+    pub fn booster_pick_rates(&self) -> Vec<(Booster, f64)> {
+        let total = self.booster_picks.iter().sum();
+        Booster::all()
+            .into_iter()
+            .zip(&self.booster_picks)
+            .map(|(booster, &picks)| (booster, rate(picks, total)))
+            .collect()
+    }
+}
+
+pub fn parallel_simulate<A, F>(
+    config: Config,
+    agents: F,
+    games: u64,
+    seed: u64,
+    threads: usize,
+) -> Result<SimulationSummary, ()>
+where
+    A: Agent,
+    F: Fn(u64) -> Vec<A> + Sync,
+{
+    if !config.has_stalemate_limit() {
+        return Err(());
+    }
+
+    let threads = threads.max(1) as u64;
+    let player_count = usize::from(config.player_count);
+    let config = &config;
+    let agents = &agents;
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|worker| {
+                scope.spawn(move || {
+                    let mut summary = SimulationSummary::new(player_count);
+                    let mut game = worker;
+                    while game < games {
+                        play(config, agents(seed.wrapping_add(game)), &mut summary)?;
+                        game += threads;
+                    }
+                    Ok(summary)
+                })
+            })
+            .collect();
+
+        let mut summary = SimulationSummary::new(player_count);
+        for worker in workers {
+            summary.merge(&worker.join().map_err(|_| ())??);
+        }
+        Ok(summary)
+    })
+}
+
+fn play<A: Agent>(
+    config: &Config,
+    agents: Vec<A>,
+    summary: &mut SimulationSummary,
+) -> Result<(), ()> {
+    let mut driver = Driver::new(config.clone(), agents)?;
+    for outcome in driver.run()? {
+        summary.total_choices += 1;
+        match outcome {
+            Outcome::CharacterPhaseDone(headstarts) => {
+                for headstart in headstarts {
+                    summary.character_picks[usize::from(headstart.0.to_index())] += 1;
+                }
+            }
+            Outcome::BoosterPhaseDone(boosters) => {
+                for booster in boosters {
+                    summary.booster_picks[usize::from(booster.to_index())] += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let game = driver.game();
+    summary.games += 1;
+    summary.total_rounds += u64::from(game.round_number());
+    match game.winner_index() {
+        Some(winner) => summary.wins[winner] += 1,
+        None => summary.draws += 1,
+    }
+    Ok(())
+}
+
+fn add(totals: &mut [u64], other: &[u64]) {
+    for (total, value) in totals.iter_mut().zip(other) {
+        *total += value;
+    }
+}
+
+pub(super) fn rate(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{GreedyAgent, RandomAgent};
+
+    fn random_agents(seed: u64) -> Vec<RandomAgent> {
+        vec![RandomAgent::new(seed), RandomAgent::new(seed ^ 0xFF)]
+    }
+
+    fn limited_config() -> Config {
+        Config {
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(50),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn results_do_not_depend_on_thread_count() {
+        let one = parallel_simulate(limited_config(), random_agents, 12, 5, 1).unwrap();
+        let many = parallel_simulate(limited_config(), random_agents, 12, 5, 4).unwrap();
+        assert_eq!(one, many);
+    }
+
+    #[test]
+    fn every_game_is_counted() {
+        let summary = parallel_simulate(limited_config(), random_agents, 10, 0, 3).unwrap();
+        assert_eq!(10, summary.games);
+        assert_eq!(10, summary.wins.iter().sum::<u64>() + summary.draws);
+        assert!((summary.win_rates().iter().sum::<f64>() + summary.draw_rate() - 1.0).abs() < 1e-9);
+        assert!(summary.average_choices() > 0.0);
+        assert!(summary.average_rounds() > 0.0);
+    }
+
+    #[test]
+    fn pick_rates_are_distributions() {
+        let summary = parallel_simulate(limited_config(), random_agents, 10, 0, 2).unwrap();
+        let characters: f64 = summary.character_pick_rates().iter().map(|(_, r)| r).sum();
+        let boosters: f64 = summary.booster_pick_rates().iter().map(|(_, r)| r).sum();
+        assert!((characters - 1.0).abs() < 1e-9);
+        assert!((boosters - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stronger_agent_wins_more() {
+        let summary = parallel_simulate(
+            limited_config(),
+            |seed| -> Vec<Box<dyn Agent>> {
+                vec![Box::new(GreedyAgent), Box::new(RandomAgent::new(seed))]
+            },
+            20,
+            0,
+            2,
+        )
+        .unwrap();
+        assert!(summary.wins[0] > summary.wins[1]);
+    }
+
+    #[test]
+    fn stalemate_limit_is_required() {
+        assert!(parallel_simulate(Config::default(), random_agents, 1, 0, 1).is_err());
+    }
+
+    #[test]
+    fn wrong_agent_count_is_an_error() {
+        let result = parallel_simulate(
+            limited_config(),
+            |seed| vec![RandomAgent::new(seed)],
+            2,
+            0,
+            2,
+        );
+        assert!(result.is_err());
+    }
+}
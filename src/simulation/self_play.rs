@@ -0,0 +1,405 @@
+use crate::agents::{Agent, Driver};
+use crate::env::{choice_to_index, legal_action_mask, observe, terminal_rewards};
+use crate::game::Config;
+
+use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+use std::sync::mpsc;
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordFormat {
+    Jsonl,
+    Binary,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub game: u64,
+    pub player_index: u8,
+    pub observation: Vec<f32>,
+    pub legal_mask: Vec<bool>,
+    pub action: u16,
+    pub result: f32,
+}
+
+impl Record {
+    pub fn write<W: Write>(&self, writer: &mut W, format: RecordFormat) -> io::Result<()> {
+        match format {
+            RecordFormat::Jsonl => self.write_jsonl(writer),
+            RecordFormat::Binary => self.write_binary(writer),
+        }
+    }
+
+    fn write_jsonl<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
+            "{{\"game\":{},\"player\":{},\"observation\":[",
+            self.game, self.player_index
+        )?;
+        for (i, value) in self.observation.iter().enumerate() {
+            if i > 0 {
+                write!(writer, ",")?;
+            }
+            write!(writer, "{}", value)?;
+        }
+        write!(writer, "],\"legal_mask\":[")?;
+        for (i, legal) in self.legal_mask.iter().enumerate() {
+            if i > 0 {
+                write!(writer, ",")?;
+            }
+            write!(writer, "{}", u8::from(*legal))?;
+        }
+        writeln!(
+            writer,
+            "],\"action\":{},\"result\":{}}}",
+            self.action, self.result
+        )
+    }
+
+    fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.game.to_le_bytes())?;
+        writer.write_all(&[self.player_index])?;
+        writer.write_all(&(self.observation.len() as u16).to_le_bytes())?;
+        for value in &self.observation {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        writer.write_all(&(self.legal_mask.len() as u16).to_le_bytes())?;
+        let mut packed = vec![0u8; (self.legal_mask.len() + 7) / 8];
+        for (i, legal) in self.legal_mask.iter().enumerate() {
+            if *legal {
+                packed[i / 8] |= 1 << (i % 8);
+            }
+        }
+        writer.write_all(&packed)?;
+        writer.write_all(&self.action.to_le_bytes())?;
+        writer.write_all(&self.result.to_le_bytes())
+    }
+
+    pub fn read_binary<R: Read>(reader: &mut R) -> io::Result<Option<Record>> {
+        let mut game = [0; 8];
+        match reader.read_exact(&mut game) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let mut player_index = [0; 1];
+        reader.read_exact(&mut player_index)?;
+
+        let observation_len = read_u16(reader)?;
+        let mut observation = Vec::with_capacity(usize::from(observation_len));
+        for _ in 0..observation_len {
+            let mut value = [0; 4];
+            reader.read_exact(&mut value)?;
+            observation.push(f32::from_le_bytes(value));
+        }
+
+        let mask_len = usize::from(read_u16(reader)?);
+        let mut packed = vec![0u8; (mask_len + 7) / 8];
+        reader.read_exact(&mut packed)?;
+        let legal_mask = (0..mask_len)
+            .map(|i| packed[i / 8] & (1 << (i % 8)) != 0)
+            .collect();
+
+        let action = read_u16(reader)?;
+        let mut result = [0; 4];
+        reader.read_exact(&mut result)?;
+
+        Ok(Some(Record {
+            game: u64::from_le_bytes(game),
+            player_index: player_index[0],
+            observation,
+            legal_mask,
+            action,
+            result: f32::from_le_bytes(result),
+        }))
+    }
+}
+
+fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16> {
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelfPlayConfig {
+    pub game: Config,
+    pub games: u64,
+    pub threads: usize,
+    pub seed: u64,
+    pub format: RecordFormat,
+}
+
+impl Default for SelfPlayConfig {
+    fn default() -> SelfPlayConfig {
+        SelfPlayConfig {
+            game: Config {
+                max_state_repetitions: Some(3),
+                max_rounds_without_progress: Some(50),
+                ..Config::default()
+            },
+            games: 1000,
+            threads: 1,
+            seed: 0,
+            format: RecordFormat::Jsonl,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SelfPlaySummary {
+    pub games: u64,
+    pub records: u64,
+    pub wins: Vec<u64>,
+    pub draws: u64,
+}
+
+struct PlayedGame {
+    bytes: Vec<u8>,
+    records: u64,
+    winner: Option<usize>,
+}
+
+pub fn self_play<A, F, W>(
+    config: &SelfPlayConfig,
+    make_agents: F,
+    mut writer: W,
+) -> io::Result<SelfPlaySummary>
+where
+    A: Agent,
+    F: Fn(u64) -> Vec<A> + Sync,
+    W: Write,
+{
+    if !config.game.has_stalemate_limit() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "game config has no stalemate limit",
+        ));
+    }
+
+    let threads = config.threads.max(1) as u64;
+    let (sender, receiver) =
+        mpsc::sync_channel::<(u64, io::Result<PlayedGame>)>(4 * threads as usize);
+    let mut summary = SelfPlaySummary {
+        wins: vec![0; usize::from(config.game.player_count)],
+        ..SelfPlaySummary::default()
+    };
+
+    thread::scope(|scope| {
+        for worker in 0..threads {
+            let sender = sender.clone();
+            let make_agents = &make_agents;
+            scope.spawn(move || {
+                let mut game = worker;
+                while game < config.games {
+                    let played = play_game(config, game, make_agents);
+                    let failed = played.is_err();
+                    if sender.send((game, played)).is_err() || failed {
+                        break;
+                    }
+                    game += threads;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (game, played) in receiver {
+            pending.insert(game, played?);
+            while let Some(played) = pending.remove(&next) {
+                writer.write_all(&played.bytes)?;
+                summary.games += 1;
+                summary.records += played.records;
+                match played.winner {
+                    Some(winner) => summary.wins[winner] += 1,
+                    None => summary.draws += 1,
+                }
+                next += 1;
+            }
+        }
+        writer.flush()
+    })?;
+
+    Ok(summary)
+}
+
+fn play_game<A, F>(config: &SelfPlayConfig, game: u64, make_agents: &F) -> io::Result<PlayedGame>
+where
+    A: Agent,
+    F: Fn(u64) -> Vec<A>,
+{
+    let invalid = || io::Error::new(io::ErrorKind::InvalidInput, "agents cannot play this game");
+    let agents = make_agents(config.seed.wrapping_add(game));
+    let mut driver = Driver::new(config.game.clone(), agents).map_err(|_| invalid())?;
+    let mut records = vec![];
+
+    while !driver.game().is_over() {
+        let player_count = driver.game().player_count();
+        let observations: Vec<(Vec<f32>, Vec<bool>)> = (0..player_count)
+            .map(|i| {
+                (
+                    observe(driver.game(), i),
+                    legal_action_mask(driver.game(), i),
+                )
+            })
+            .collect();
+        let choice = driver.decide().map_err(|_| invalid())?;
+        for (i, ((observation, legal_mask), chosen)) in observations
+            .into_iter()
+            .zip(choice.to_choices())
+            .enumerate()
+        {
+            records.push(Record {
+                game,
+                player_index: i as u8,
+                observation,
+                legal_mask,
+                action: choice_to_index(chosen) as u16,
+                result: 0.0,
+            });
+        }
+        driver.apply(choice).map_err(|_| invalid())?;
+    }
+
+    let winner = driver.game().winner_index();
+    let rewards = terminal_rewards(driver.game());
+    let mut bytes = vec![];
+    for record in &mut records {
+        record.result = rewards[usize::from(record.player_index)] as f32;
+        record.write(&mut bytes, config.format)?;
+    }
+
+    Ok(PlayedGame {
+        bytes,
+        records: records.len() as u64,
+        winner,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::RandomAgent;
+    use crate::env::{observation_len, ACTION_SPACE_SIZE};
+
+    fn random_agents(seed: u64) -> Vec<RandomAgent> {
+        vec![RandomAgent::new(seed), RandomAgent::new(seed + 1)]
+    }
+
+    fn config(threads: usize, format: RecordFormat) -> SelfPlayConfig {
+        SelfPlayConfig {
+            games: 6,
+            threads,
+            format,
+            ..SelfPlayConfig::default()
+        }
+    }
+
+    #[test]
+    fn summary_counts_every_game() {
+        let mut bytes = vec![];
+        let summary =
+            self_play(&config(3, RecordFormat::Jsonl), random_agents, &mut bytes).unwrap();
+
+        assert_eq!(6, summary.games);
+        assert_eq!(6, summary.wins.iter().sum::<u64>() + summary.draws);
+        let lines = String::from_utf8(bytes).unwrap();
+        assert_eq!(summary.records, lines.lines().count() as u64);
+        assert!(lines.lines().all(|l| l.starts_with("{\"game\":")));
+    }
+
+    #[test]
+    fn binary_records_round_trip() {
+        let mut bytes = vec![];
+        let summary =
+            self_play(&config(2, RecordFormat::Binary), random_agents, &mut bytes).unwrap();
+
+        let mut reader = &bytes[..];
+        let mut records = vec![];
+        while let Some(record) = Record::read_binary(&mut reader).unwrap() {
+            records.push(record);
+        }
+        assert_eq!(summary.records, records.len() as u64);
+        for record in records {
+            assert_eq!(
+                observation_len(&Config::default()),
+                record.observation.len()
+            );
+            assert_eq!(ACTION_SPACE_SIZE, record.legal_mask.len());
+            assert!(record.legal_mask[usize::from(record.action)]);
+        }
+    }
+
+    #[test]
+    fn results_match_env_terminal_rewards() {
+        let mut config = config(1, RecordFormat::Binary);
+        config.game.player_count = 3;
+        let mut bytes = vec![];
+        let agents = |seed| (seed..seed + 3).map(RandomAgent::new).collect();
+        self_play(&config, agents, &mut bytes).unwrap();
+
+        let mut reader = &bytes[..];
+        let mut records = vec![];
+        while let Some(record) = Record::read_binary(&mut reader).unwrap() {
+            records.push(record);
+        }
+        for step in records.chunks(3) {
+            let results: Vec<f32> = step.iter().map(|r| r.result).collect();
+            assert_eq!(0.0, results.iter().sum::<f32>());
+            assert!(results.iter().all(|&r| r == 2.0 || r == -1.0 || r == 0.0));
+        }
+        assert!(records.iter().any(|r| r.result == 2.0));
+    }
+
+    #[test]
+    fn thread_count_does_not_change_totals() {
+        let one = self_play(&config(1, RecordFormat::Binary), random_agents, io::sink()).unwrap();
+        let many = self_play(&config(4, RecordFormat::Binary), random_agents, io::sink()).unwrap();
+        assert_eq!(one, many);
+    }
+
+    #[test]
+    fn records_are_written_in_game_order() {
+        let mut one = vec![];
+        self_play(&config(1, RecordFormat::Jsonl), random_agents, &mut one).unwrap();
+        let mut many = vec![];
+        self_play(&config(4, RecordFormat::Jsonl), random_agents, &mut many).unwrap();
+
+        assert_eq!(one, many);
+        let games: Vec<u64> = String::from_utf8(many)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                line["{\"game\":".len()..]
+                    .split(',')
+                    .next()
+                    .unwrap()
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+        assert!(games.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn stalemate_limit_is_required() {
+        let config = SelfPlayConfig {
+            game: Config::default(),
+            ..config(1, RecordFormat::Jsonl)
+        };
+        let result = self_play(&config, random_agents, io::sink());
+        assert_eq!(io::ErrorKind::InvalidInput, result.unwrap_err().kind());
+    }
+
+    #[test]
+    fn wrong_agent_count_is_an_error() {
+        let result = self_play(
+            &config(1, RecordFormat::Jsonl),
+            |seed| vec![RandomAgent::new(seed)],
+            io::sink(),
+        );
+        assert!(result.is_err());
+    }
+}
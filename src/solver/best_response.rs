@@ -0,0 +1,373 @@
+use super::{utility, StrategyProfile};
+use crate::agents::{Agent, View};
+use crate::choices::{BatchChoice, Choice, Choices};
+use crate::game::{BatchChoiceGame, FullState};
+
+use std::collections::{HashMap, HashSet};
+
+pub trait Policy {
+    fn strategy(&mut self, game: &BatchChoiceGame, player_index: usize) -> Vec<(Choice, f64)>;
+}
+
+impl Policy for StrategyProfile {
+    fn strategy(&mut self, game: &BatchChoiceGame, player_index: usize) -> Vec<(Choice, f64)> {
+        StrategyProfile::strategy(self, game, player_index).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AgentPolicy<A: Agent> {
+    agent: A,
+    samples: u32,
+}
+
+impl<A: Agent> AgentPolicy<A> {
+    pub fn new(agent: A, samples: u32) -> Self {
+        Self {
+            agent,
+            samples: samples.max(1),
+        }
+    }
+
+    pub fn into_agent(self) -> A {
+        self.agent
+    }
+
+    fn sample(&mut self, view: View, choices: &Choices) -> Option<Choice> {
+        let choice = match choices {
+            Choices::Characters(characters) => self.agent.choose_character(view, characters).into(),
+            Choices::Boosters(boosters) => self.agent.choose_booster(view, boosters).into(),
+            Choices::DequeueChoices(dequeue_choices) => self
+                .agent
+                .choose_dequeue_choice(view, dequeue_choices)
+                .into(),
+            Choices::Actions(actions) => self.agent.choose_action(view, actions).into(),
+            Choices::None => return None,
+        };
+        Some(choice)
+    }
+}
+
+impl<A: Agent> Policy for AgentPolicy<A> {
+    fn strategy(&mut self, game: &BatchChoiceGame, player_index: usize) -> Vec<(Choice, f64)> {
+        let choices = match game.choices_for(player_index) {
+            Some(choices) => choices,
+            None => return vec![],
+        };
+        let view = View { game, player_index };
+        let mut counts: Vec<(Choice, u32)> = vec![];
+        for _ in 0..self.samples {
+            if let Some(choice) = self.sample(view, &choices) {
+                match counts.iter_mut().find(|(c, _)| *c == choice) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((choice, 1)),
+                }
+            }
+        }
+        let samples = f64::from(self.samples);
+        counts
+            .into_iter()
+            .map(|(choice, count)| (choice, f64::from(count) / samples))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BestResponseConfig {
+    pub max_depth: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BestResponse {
+    pub choice: Choice,
+    pub value: f64,
+}
+
+pub fn best_response<P: Policy>(
+    game: &BatchChoiceGame,
+    responder: usize,
+    policy: &mut P,
+    config: &BestResponseConfig,
+) -> Option<BestResponse> {
+    if game.player_count() != 2 || responder > 1 || game.is_over() {
+        return None;
+    }
+
+    let mut search = Search {
+        responder,
+        policy,
+        table: HashMap::new(),
+        path: HashSet::new(),
+    };
+    let (choice, value, _) = search.respond(game, config.max_depth);
+    choice.map(|choice| BestResponse { choice, value })
+}
+
+pub fn exploitability<P: Policy>(
+    game: &BatchChoiceGame,
+    policy: &mut P,
+    config: &BestResponseConfig,
+) -> Option<f64> {
+    let first = best_response(game, 0, policy, config)?;
+    let second = best_response(game, 1, policy, config)?;
+    Some((first.value + second.value) / 2.0)
+}
+
+struct Search<'p, P> {
+    responder: usize,
+    policy: &'p mut P,
+    table: HashMap<(FullState, Option<u32>), (Option<Choice>, f64)>,
+    path: HashSet<FullState>,
+}
+
+impl<P: Policy> Search<'_, P> {
+    fn respond(
+        &mut self,
+        game: &BatchChoiceGame,
+        depth: Option<u32>,
+    ) -> (Option<Choice>, f64, bool) {
+        let responder = self.responder;
+        let mut choices = game.choices().to_choices();
+        if choices.is_empty() || depth == Some(0) {
+            return (None, utility(game, responder), false);
+        }
+        let key = (game.full_state(), depth);
+        if let Some(&(choice, value)) = self.table.get(&key) {
+            return (choice, value, false);
+        }
+        if !self.path.insert(key.0.clone()) {
+            return (None, utility(game, responder), true);
+        }
+
+        let opponent = 1 - responder;
+        let opponent_strategy: Vec<(Choice, f64)> = self
+            .policy
+            .strategy(game, opponent)
+            .into_iter()
+            .filter(|(_, p)| *p > 0.0)
+            .collect();
+        let own_choices = choices.swap_remove(responder);
+
+        let mut best: (Option<Choice>, f64) = (None, f64::NEG_INFINITY);
+        let mut cut = false;
+        for choice in own_choices {
+            let mut value = 0.0;
+            for (opponent_choice, p) in &opponent_strategy {
+                let mut joint = vec![choice, *opponent_choice];
+                if responder == 1 {
+                    joint.swap(0, 1);
+                }
+                let mut next = game.clone();
+                next.choose(BatchChoice::from_choices(joint).expect("choices share a phase"))
+                    .expect("choices are legal");
+                let (_, next_value, next_cut) = self.respond(&next, depth.map(|d| d - 1));
+                value += p * next_value;
+                cut |= next_cut;
+            }
+            if value > best.1 {
+                best = (Some(choice), value);
+            }
+        }
+        self.path.remove(&key.0);
+        if !cut {
+            self.table.insert(key, best);
+        }
+        (best.0, best.1, cut)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::FirstLegalAgent;
+    use crate::choices::{ArsenalItem, Booster, Character, DequeueChoice};
+    use crate::game::Config;
+
+    fn action_phase_game() -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        game
+    }
+
+    #[test]
+    fn agent_policy_of_deterministic_agent_is_pure() {
+        let game = BatchChoiceGame::default();
+        let mut policy = AgentPolicy::new(FirstLegalAgent, 5);
+        let strategy = policy.strategy(&game, 0);
+        assert_eq!(1, strategy.len());
+        assert_eq!(1.0, strategy[0].1);
+    }
+
+    #[test]
+    fn best_response_punishes_predictable_action() {
+        let game = action_phase_game();
+        let mut policy = AgentPolicy::new(FirstLegalAgent, 1);
+        let response = best_response(
+            &game,
+            0,
+            &mut policy,
+            &BestResponseConfig { max_depth: Some(1) },
+        )
+        .unwrap();
+
+        assert!(response.choice.action().is_some());
+        assert!(response.value > 0.0);
+    }
+
+    #[test]
+    fn uniform_profile_is_not_negatively_exploitable() {
+        let game = BatchChoiceGame::new(Config {
+            points_to_win: 2,
+            ..Config::default()
+        });
+        let mut profile = StrategyProfile::new();
+        let exploitability = exploitability(
+            &game,
+            &mut profile,
+            &BestResponseConfig { max_depth: Some(2) },
+        )
+        .unwrap();
+        assert!(exploitability > -1e-9);
+    }
+
+    #[test]
+    fn deterministic_agent_is_exploitable() {
+        let game = action_phase_game();
+        let mut policy = AgentPolicy::new(FirstLegalAgent, 1);
+        let exploitability = exploitability(
+            &game,
+            &mut policy,
+            &BestResponseConfig { max_depth: Some(1) },
+        )
+        .unwrap();
+        assert!(exploitability > 0.0);
+    }
+
+    #[test]
+    fn best_response_returns_none_for_finished_game() {
+        let mut game = action_phase_game();
+        while !game.is_over() {
+            let choice = game.choices().to_choices();
+            game.choose(BatchChoice::from_choices(vec![choice[0][0], choice[1][0]]).unwrap())
+                .unwrap();
+        }
+        let mut policy = StrategyProfile::new();
+        assert!(best_response(&game, 0, &mut policy, &BestResponseConfig::default()).is_none());
+    }
+
+    #[test]
+    fn depth_is_unlimited_by_default() {
+        assert_eq!(None, BestResponseConfig::default().max_depth);
+    }
+
+    #[test]
+    fn unlimited_best_response_plays_to_the_end() {
+        let mut game = BatchChoiceGame::new(Config {
+            points_to_win: 1,
+            max_state_repetitions: Some(1),
+            max_rounds_without_progress: Some(1),
+            ..Config::default()
+        });
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        let mut policy = AgentPolicy::new(FirstLegalAgent, 1);
+        let response =
+            best_response(&game, 0, &mut policy, &BestResponseConfig::default()).unwrap();
+
+        assert!(-1.0 <= response.value && response.value <= 1.0);
+        assert!(response.value > 0.0);
+    }
+
+    struct CountingPolicy {
+        profile: StrategyProfile,
+        calls: usize,
+    }
+
+    impl Policy for CountingPolicy {
+        fn strategy(&mut self, game: &BatchChoiceGame, player_index: usize) -> Vec<(Choice, f64)> {
+            self.calls += 1;
+            Policy::strategy(&mut self.profile, game, player_index)
+        }
+    }
+
+    #[test]
+    fn each_reachable_state_is_evaluated_once() {
+        let mut game = BatchChoiceGame::new(Config {
+            points_to_win: 1,
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(2),
+            ..Config::default()
+        });
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::None, Booster::None]))
+            .unwrap();
+        let mut seen = HashSet::new();
+        let mut stack = vec![game.clone()];
+        while let Some(game) = stack.pop() {
+            if game.is_over() || !seen.insert(game.full_state()) {
+                continue;
+            }
+            let choices = game.choices().to_choices();
+            for &row in &choices[0] {
+                for &column in &choices[1] {
+                    let mut next = game.clone();
+                    next.choose(BatchChoice::from_choices(vec![row, column]).unwrap())
+                        .unwrap();
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut policy = CountingPolicy {
+            profile: StrategyProfile::new(),
+            calls: 0,
+        };
+        best_response(&game, 0, &mut policy, &BestResponseConfig::default()).unwrap();
+        assert_eq!(seen.len(), policy.calls);
+    }
+
+    #[test]
+    fn repeated_state_is_scored_as_draw() {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        let mut policy = StrategyProfile::new();
+        let mut search = Search {
+            responder: 0,
+            policy: &mut policy,
+            table: HashMap::new(),
+            path: HashSet::new(),
+        };
+        search.path.insert(game.full_state());
+
+        let (choice, value, cut) = search.respond(&game, None);
+        assert_eq!(None, choice);
+        assert!(cut);
+        assert!(value > 0.0);
+        assert_eq!(utility(&game, 0), value);
+        assert!(search.table.is_empty());
+    }
+}
@@ -0,0 +1,424 @@
+use super::utility;
+use crate::choices::{BatchChoice, Choice};
+use crate::game::BatchChoiceGame;
+use crate::helpers::StableHasher;
+use crate::random::Prng;
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::hash::Hasher;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CfrVariant {
+    Cfr,
+    CfrPlus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CfrConfig {
+    pub iterations: u32,
+    pub max_depth: Option<u32>,
+    pub variant: CfrVariant,
+    pub seed: u64,
+}
+
+impl Default for CfrConfig {
+    fn default() -> CfrConfig {
+        CfrConfig {
+            iterations: 1000,
+            max_depth: Some(6),
+            variant: CfrVariant::CfrPlus,
+            seed: 0,
+        }
+    }
+}
+
+pub fn information_state_key(game: &BatchChoiceGame, player_index: usize) -> u64 {
+    let mut hasher = StableHasher::default();
+    hasher.write(&game.zobrist_key().to_le_bytes());
+    hasher.write(&(player_index as u64).to_le_bytes());
+    hasher.finish()
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StrategyProfile {
+    strategies: HashMap<u64, Vec<f64>>,
+}
+
+impl StrategyProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.strategies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strategies.is_empty()
+    }
+
+    pub fn get(&self, key: u64) -> Option<&[f64]> {
+        self.strategies.get(&key).map(|s| &s[..])
+    }
+
+    pub fn insert(&mut self, key: u64, strategy: Vec<f64>) {
+        self.strategies.insert(key, strategy);
+    }
+
+    pub fn strategy(
+        &self,
+        game: &BatchChoiceGame,
+        player_index: usize,
+    ) -> Option<Vec<(Choice, f64)>> {
+        let choices = game.choices().to_choices().into_iter().nth(player_index)?;
+        let strategy = match self.get(information_state_key(game, player_index)) {
+            Some(strategy) if strategy.len() == choices.len() => strategy.to_vec(),
+            _ => vec![1.0 / choices.len() as f64; choices.len()],
+        };
+        Some(choices.into_iter().zip(strategy).collect())
+    }
+
+    pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let mut keys: Vec<&u64> = self.strategies.keys().collect();
+        keys.sort();
+        for key in keys {
+            write!(writer, "{:016x}", key)?;
+            for p in &self.strategies[key] {
+                write!(writer, " {}", p)?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+
+    pub fn read<R: BufRead>(reader: R) -> io::Result<StrategyProfile> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid strategy line: {}", line),
+            )
+        };
+        let mut profile = StrategyProfile::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let key = fields
+                .next()
+                .and_then(|k| u64::from_str_radix(k, 16).ok())
+                .ok_or_else(|| invalid(&line))?;
+            let strategy = fields
+                .map(|p| p.parse::<f64>())
+                .collect::<Result<Vec<f64>, _>>()
+                .map_err(|_| invalid(&line))?;
+            profile.insert(key, strategy);
+        }
+        Ok(profile)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write(File::create(path)?)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<StrategyProfile> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct InformationNode {
+    regret_sum: Vec<f64>,
+    strategy_sum: Vec<f64>,
+}
+
+impl InformationNode {
+    fn new(choice_count: usize) -> Self {
+        Self {
+            regret_sum: vec![0.0; choice_count],
+            strategy_sum: vec![0.0; choice_count],
+        }
+    }
+
+    fn current_strategy(&self) -> Vec<f64> {
+        let positive: Vec<f64> = self.regret_sum.iter().map(|r| r.max(0.0)).collect();
+        let total: f64 = positive.iter().sum();
+        if total > 0.0 {
+            positive.iter().map(|r| r / total).collect()
+        } else {
+            vec![1.0 / positive.len() as f64; positive.len()]
+        }
+    }
+
+    fn average_strategy(&self) -> Vec<f64> {
+        let total: f64 = self.strategy_sum.iter().sum();
+        if total > 0.0 {
+            self.strategy_sum.iter().map(|s| s / total).collect()
+        } else {
+            vec![1.0 / self.strategy_sum.len() as f64; self.strategy_sum.len()]
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CfrTrainer {
+    config: CfrConfig,
+    rng: Prng,
+    nodes: HashMap<u64, InformationNode>,
+    path: HashSet<u64>,
+}
+
+impl CfrTrainer {
+    pub fn new(config: CfrConfig) -> Self {
+        let rng = Prng::new(config.seed);
+        Self {
+            config,
+            rng,
+            nodes: HashMap::new(),
+            path: HashSet::new(),
+        }
+    }
+
+    pub fn train(&mut self, game: &BatchChoiceGame) -> Result<StrategyProfile, ()> {
+        if game.player_count() != 2 {
+            return Err(());
+        }
+
+        for _ in 0..self.config.iterations {
+            for traverser in 0..2 {
+                self.traverse(game, traverser, 0);
+            }
+        }
+
+        Ok(self.profile())
+    }
+
+    pub fn profile(&self) -> StrategyProfile {
+        StrategyProfile {
+            strategies: self
+                .nodes
+                .iter()
+                .map(|(key, node)| (*key, node.average_strategy()))
+                .collect(),
+        }
+    }
+
+    fn traverse(&mut self, game: &BatchChoiceGame, traverser: usize, depth: u32) -> f64 {
+        let choices = game.choices().to_choices();
+        if choices.is_empty() || matches!(self.config.max_depth, Some(max) if depth >= max) {
+            return utility(game, traverser);
+        }
+        if !self.path.insert(game.zobrist_key()) {
+            return utility(game, traverser);
+        }
+
+        let opponent = 1 - traverser;
+        let opponent_key = information_state_key(game, opponent);
+        let opponent_strategy = self
+            .nodes
+            .entry(opponent_key)
+            .or_insert_with(|| InformationNode::new(choices[opponent].len()))
+            .current_strategy();
+        let opponent_choice = choices[opponent][sample(&opponent_strategy, &mut self.rng)];
+        if let Some(node) = self.nodes.get_mut(&opponent_key) {
+            for (sum, p) in node.strategy_sum.iter_mut().zip(&opponent_strategy) {
+                *sum += p;
+            }
+        }
+
+        let key = information_state_key(game, traverser);
+        let strategy = self
+            .nodes
+            .entry(key)
+            .or_insert_with(|| InformationNode::new(choices[traverser].len()))
+            .current_strategy();
+
+        let utilities: Vec<f64> = choices[traverser]
+            .iter()
+            .map(|&choice| {
+                let mut joint = vec![choice, opponent_choice];
+                if traverser == 1 {
+                    joint.swap(0, 1);
+                }
+                let mut next = game.clone();
+                next.choose(BatchChoice::from_choices(joint).expect("choices share a phase"))
+                    .expect("choices are legal");
+                self.traverse(&next, traverser, depth + 1)
+            })
+            .collect();
+        self.path.remove(&game.zobrist_key());
+        let value: f64 = strategy.iter().zip(&utilities).map(|(p, u)| p * u).sum();
+
+        let variant = self.config.variant;
+        if let Some(node) = self.nodes.get_mut(&key) {
+            for (regret, u) in node.regret_sum.iter_mut().zip(&utilities) {
+                *regret += u - value;
+                if variant == CfrVariant::CfrPlus {
+                    *regret = regret.max(0.0);
+                }
+            }
+        }
+
+        value
+    }
+}
+
+pub(crate) fn sample(strategy: &[f64], rng: &mut Prng) -> usize {
+    let mut threshold = rng.next_f64();
+    for (i, p) in strategy.iter().enumerate() {
+        if threshold < *p {
+            return i;
+        }
+        threshold -= p;
+    }
+    strategy.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{Booster, Character};
+    use crate::game::Config;
+
+    fn small_config() -> CfrConfig {
+        CfrConfig {
+            iterations: 20,
+            max_depth: Some(2),
+            ..CfrConfig::default()
+        }
+    }
+
+    fn small_game() -> BatchChoiceGame {
+        BatchChoiceGame::new(Config {
+            points_to_win: 2,
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn train_fails_for_more_than_two_players() {
+        let game = BatchChoiceGame::new(Config {
+            player_count: 3,
+            ..Config::default()
+        });
+        assert!(CfrTrainer::new(small_config()).train(&game).is_err());
+    }
+
+    #[test]
+    fn trained_strategies_are_distributions() {
+        let profile = CfrTrainer::new(small_config())
+            .train(&small_game())
+            .unwrap();
+
+        assert!(!profile.is_empty());
+        for strategy in profile.strategies.values() {
+            let total: f64 = strategy.iter().sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn strategy_covers_root_choices() {
+        let game = small_game();
+        let profile = CfrTrainer::new(small_config()).train(&game).unwrap();
+        let strategy = profile.strategy(&game, 0).unwrap();
+        assert_eq!(4, strategy.len());
+    }
+
+    #[test]
+    fn write_and_read_round_trip() {
+        let profile = CfrTrainer::new(small_config())
+            .train(&small_game())
+            .unwrap();
+        let mut bytes = vec![];
+        profile.write(&mut bytes).unwrap();
+        let read = StrategyProfile::read(&bytes[..]).unwrap();
+        assert_eq!(profile, read);
+    }
+
+    #[test]
+    fn read_rejects_garbage() {
+        assert!(StrategyProfile::read(&b"not a strategy"[..]).is_err());
+    }
+
+    #[test]
+    fn information_state_key_depends_on_player() {
+        let game = small_game();
+        assert_ne!(
+            information_state_key(&game, 0),
+            information_state_key(&game, 1)
+        );
+    }
+
+    #[test]
+    fn information_state_key_is_pinned() {
+        let game = BatchChoiceGame::default();
+        assert_eq!(0x17ef_db7c_8912_4f15, information_state_key(&game, 0));
+    }
+
+    #[test]
+    fn default_depth_trains_small_game_quickly() {
+        assert_eq!(Some(6), CfrConfig::default().max_depth);
+        let profile = CfrTrainer::new(CfrConfig {
+            iterations: 10,
+            ..CfrConfig::default()
+        })
+        .train(&small_game())
+        .unwrap();
+        assert!(profile.strategy(&small_game(), 0).is_some());
+    }
+
+    #[test]
+    fn repeated_position_is_scored_as_draw() {
+        let mut game = small_game();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        let mut trainer = CfrTrainer::new(CfrConfig::default());
+        trainer.path.insert(game.zobrist_key());
+
+        let value = trainer.traverse(&game, 0, 0);
+        assert!(value > 0.0);
+        assert_eq!(utility(&game, 0), value);
+    }
+
+    #[test]
+    fn trains_to_the_end_of_the_game_without_depth_limit() {
+        let mut game = BatchChoiceGame::new(Config {
+            points_to_win: 1,
+            max_state_repetitions: Some(1),
+            max_rounds_without_progress: Some(1),
+            ..Config::default()
+        });
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        let profile = CfrTrainer::new(CfrConfig {
+            iterations: 5,
+            max_depth: None,
+            ..CfrConfig::default()
+        })
+        .train(&game)
+        .unwrap();
+        assert!(profile.strategy(&game, 0).is_some());
+    }
+
+    #[test]
+    fn sample_respects_pure_strategy() {
+        let mut rng = Prng::new(0);
+        for _ in 0..10 {
+            assert_eq!(2, sample(&[0.0, 0.0, 1.0], &mut rng));
+        }
+    }
+}
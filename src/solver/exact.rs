@@ -0,0 +1,308 @@
+use super::{solve_zero_sum, utility, Equilibrium};
+use crate::choices::{BatchChoice, Choice};
+use crate::game::{BatchChoiceGame, FullState};
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExactConfig {
+    pub max_states: usize,
+}
+
+impl Default for ExactConfig {
+    fn default() -> ExactConfig {
+        ExactConfig {
+            max_states: 1_000_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactSolution {
+    pub value: f64,
+    pub strategies: Equilibrium<Choice>,
+    pub payoffs: Vec<Vec<f64>>,
+    pub states: usize,
+}
+
+impl ExactSolution {
+    pub fn payoff(&self, row: &Choice, column: &Choice) -> Option<f64> {
+        let i = self
+            .strategies
+            .row_strategy
+            .iter()
+            .position(|(c, _)| c == row)?;
+        let j = self
+            .strategies
+            .column_strategy
+            .iter()
+            .position(|(c, _)| c == column)?;
+        Some(self.payoffs[i][j])
+    }
+}
+
+pub fn solve_exact(game: &BatchChoiceGame, config: &ExactConfig) -> Result<ExactSolution, ()> {
+    if game.player_count() != 2 || game.is_over() || game.config().max_state_repetitions.is_none() {
+        return Err(());
+    }
+
+    let mut solver = ExactSolver {
+        table: HashMap::new(),
+        path: HashSet::new(),
+        max_states: config.max_states,
+    };
+    solver.path.insert(game.full_state());
+    let choices = game.choices().to_choices();
+    let payoffs = solver.payoffs(game, &choices)?;
+    let solution = solve_zero_sum(&payoffs);
+    let mut choices = choices.into_iter();
+    let rows = choices.next().ok_or(())?;
+    let columns = choices.next().ok_or(())?;
+
+    Ok(ExactSolution {
+        value: solution.value,
+        strategies: Equilibrium {
+            row_strategy: rows.into_iter().zip(solution.row_strategy).collect(),
+            column_strategy: columns.into_iter().zip(solution.column_strategy).collect(),
+            value: solution.value,
+        },
+        payoffs,
+        states: solver.table.len(),
+    })
+}
+
+struct ExactSolver {
+    table: HashMap<FullState, f64>,
+    path: HashSet<FullState>,
+    max_states: usize,
+}
+
+impl ExactSolver {
+    fn value(&mut self, game: &BatchChoiceGame) -> Result<f64, ()> {
+        if game.is_over() {
+            return Ok(utility(game, 0));
+        }
+        let state = game.full_state();
+        if let Some(value) = self.table.get(&state) {
+            return Ok(*value);
+        }
+        if !self.path.insert(state.clone()) {
+            return Err(());
+        }
+
+        let payoffs = self.payoffs(game, &game.choices().to_choices());
+        self.path.remove(&state);
+        let value = solve_zero_sum(&payoffs?).value;
+
+        if self.table.len() >= self.max_states {
+            return Err(());
+        }
+        self.table.insert(state, value);
+        Ok(value)
+    }
+
+    fn payoffs(
+        &mut self,
+        game: &BatchChoiceGame,
+        choices: &[Vec<Choice>],
+    ) -> Result<Vec<Vec<f64>>, ()> {
+        if choices.len() != 2 {
+            return Err(());
+        }
+
+        let mut payoffs = vec![];
+        for &row in &choices[0] {
+            let mut payoff_row = vec![];
+            for &column in &choices[1] {
+                let mut next = game.clone();
+                next.choose(BatchChoice::from_choices(vec![row, column])?)?;
+                payoff_row.push(self.value(&next)?);
+            }
+            payoffs.push(payoff_row);
+        }
+        Ok(payoffs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{Booster, Character};
+    use crate::game::Config;
+
+    fn tiny_config() -> Config {
+        Config {
+            points_to_win: 1,
+            max_state_repetitions: Some(1),
+            max_rounds_without_progress: Some(1),
+            ..Config::default()
+        }
+    }
+
+    fn booster_phase_game(characters: Vec<Character>) -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::new(tiny_config());
+        game.choose(BatchChoice::Characters(characters)).unwrap();
+        game
+    }
+
+    #[test]
+    fn solve_exact_rejects_more_than_two_players() {
+        let game = BatchChoiceGame::new(Config {
+            player_count: 3,
+            ..tiny_config()
+        });
+        assert!(solve_exact(&game, &ExactConfig::default()).is_err());
+    }
+
+    #[test]
+    fn solve_exact_gives_up_past_state_limit() {
+        let game = booster_phase_game(vec![Character::Ninja, Character::Clown]);
+        assert!(solve_exact(&game, &ExactConfig { max_states: 10 }).is_err());
+    }
+
+    #[test]
+    fn strategies_are_distributions() {
+        let game = booster_phase_game(vec![Character::Ninja, Character::Clown]);
+        let solution = solve_exact(&game, &ExactConfig::default()).unwrap();
+        let row_total: f64 = solution
+            .strategies
+            .row_strategy
+            .iter()
+            .map(|(_, p)| p)
+            .sum();
+        let column_total: f64 = solution
+            .strategies
+            .column_strategy
+            .iter()
+            .map(|(_, p)| p)
+            .sum();
+
+        assert!((row_total - 1.0).abs() < 1e-6);
+        assert!((column_total - 1.0).abs() < 1e-6);
+        assert!(-1.0 <= solution.value && solution.value <= 1.0);
+        assert!(solution.states > 0);
+    }
+
+    #[test]
+    fn mirrored_matchup_is_even() {
+        let game = booster_phase_game(vec![Character::Ninja, Character::Samurai]);
+        let mirrored = booster_phase_game(vec![Character::Samurai, Character::Ninja]);
+        let value = solve_exact(&game, &ExactConfig::default()).unwrap().value;
+        let mirrored_value = solve_exact(&mirrored, &ExactConfig::default())
+            .unwrap()
+            .value;
+        assert!((value + mirrored_value).abs() < 1e-6);
+    }
+
+    #[test]
+    fn solves_character_phase() {
+        let game = BatchChoiceGame::new(tiny_config());
+        let solution = solve_exact(&game, &ExactConfig::default()).unwrap();
+        let ninja = Choice::Character(Character::Ninja);
+        let clown = Choice::Character(Character::Clown);
+
+        assert_eq!(4, solution.strategies.row_strategy.len());
+        assert_eq!(
+            solution.payoff(&ninja, &clown),
+            solution.payoff(&clown, &ninja).map(|v| -v)
+        );
+        assert!(solution.value.abs() < 1e-6);
+    }
+
+    fn dequeue_phase_game(config: Config, characters: Vec<Character>) -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::new(config);
+        game.choose(BatchChoice::Characters(characters)).unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::None, Booster::None]))
+            .unwrap();
+        game
+    }
+
+    fn reachable_states(game: &BatchChoiceGame) -> HashSet<FullState> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![game.clone()];
+        while let Some(game) = stack.pop() {
+            if game.is_over() || !seen.insert(game.full_state()) {
+                continue;
+            }
+            let choices = game.choices().to_choices();
+            for &row in &choices[0] {
+                for &column in &choices[1] {
+                    let mut next = game.clone();
+                    next.choose(BatchChoice::from_choices(vec![row, column]).unwrap())
+                        .unwrap();
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn solve_exact_rejects_games_without_state_repetition_limit() {
+        let config = Config {
+            points_to_win: 1,
+            max_state_repetitions: None,
+            max_rounds_without_progress: Some(1),
+            ..Config::default()
+        };
+        let game = dequeue_phase_game(config, vec![Character::Ninja, Character::Samurai]);
+        assert!(solve_exact(&game, &ExactConfig::default()).is_err());
+    }
+
+    #[test]
+    fn mirrored_dequeue_phase_is_even() {
+        let config = Config {
+            points_to_win: 1,
+            max_state_repetitions: Some(2),
+            max_rounds_without_progress: Some(2),
+            ..Config::default()
+        };
+        let game = dequeue_phase_game(config.clone(), vec![Character::Ninja, Character::Samurai]);
+        let mirrored = dequeue_phase_game(config, vec![Character::Samurai, Character::Ninja]);
+        let solution = solve_exact(&game, &ExactConfig::default()).unwrap();
+        let mirrored_solution = solve_exact(&mirrored, &ExactConfig::default()).unwrap();
+
+        assert!((solution.value + mirrored_solution.value).abs() < 1e-6);
+    }
+
+    #[test]
+    fn solves_each_reachable_state_once() {
+        let config = Config {
+            points_to_win: 1,
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(2),
+            ..Config::default()
+        };
+        let game = dequeue_phase_game(config, vec![Character::Ninja, Character::Samurai]);
+        let solution = solve_exact(&game, &ExactConfig::default()).unwrap();
+
+        assert_eq!(reachable_states(&game).len() - 1, solution.states);
+    }
+
+    #[test]
+    fn draws_are_scored_by_points_share() {
+        let game = BatchChoiceGame::new(Config {
+            max_state_repetitions: Some(1),
+            ..Config::default()
+        });
+        let solution = solve_exact(&game, &ExactConfig::default()).unwrap();
+        let ninja = Choice::Character(Character::Ninja);
+        let samurai = Choice::Character(Character::Samurai);
+
+        assert_eq!(Some(0.0), solution.payoff(&ninja, &ninja));
+        assert!(solution.payoff(&ninja, &samurai).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn payoff_looks_up_matrix_entries() {
+        let game = booster_phase_game(vec![Character::Ninja, Character::Clown]);
+        let solution = solve_exact(&game, &ExactConfig::default()).unwrap();
+        let shadow = Choice::Booster(Booster::Shadow);
+        let backwards = Choice::Booster(Booster::Backwards);
+        assert_eq!(
+            Some(solution.payoffs[0][0]),
+            solution.payoff(&shadow, &backwards)
+        );
+        assert_eq!(None, solution.payoff(&backwards, &shadow));
+    }
+}
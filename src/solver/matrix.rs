@@ -0,0 +1,219 @@
+use crate::choices::PointsAgainst;
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixSolution {
+    pub row_strategy: Vec<f64>,
+    pub column_strategy: Vec<f64>,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equilibrium<T> {
+    pub row_strategy: Vec<(T, f64)>,
+    pub column_strategy: Vec<(T, f64)>,
+    pub value: f64,
+}
+
+pub fn solve_points_against<T: PointsAgainst + Clone>(rows: &[T], columns: &[T]) -> Equilibrium<T> {
+    let payoffs: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| {
+                    f64::from(row.points_against(column)) - f64::from(column.points_against(row))
+                })
+                .collect()
+        })
+        .collect();
+    let solution = solve_zero_sum(&payoffs);
+
+    Equilibrium {
+        row_strategy: rows.iter().cloned().zip(solution.row_strategy).collect(),
+        column_strategy: columns
+            .iter()
+            .cloned()
+            .zip(solution.column_strategy)
+            .collect(),
+        value: solution.value,
+    }
+}
+
+pub fn solve_zero_sum(payoffs: &[Vec<f64>]) -> MatrixSolution {
+    let row_count = payoffs.len();
+    let column_count = payoffs.first().map_or(0, Vec::len);
+    assert!(
+        row_count > 0 && column_count > 0,
+        "payoff matrix must not be empty"
+    );
+
+    let min_payoff = payoffs
+        .iter()
+        .flatten()
+        .fold(f64::INFINITY, |min, &p| min.min(p));
+    let shift = 1.0 - min_payoff;
+
+    // Column player: maximize sum(y) subject to (A + shift) y <= 1 and y >= 0.
+    let width = column_count + row_count + 1;
+    let mut tableau = vec![vec![0.0; width]; row_count + 1];
+    for (i, row) in payoffs.iter().enumerate() {
+        for (j, payoff) in row.iter().enumerate() {
+            tableau[i][j] = payoff + shift;
+        }
+        tableau[i][column_count + i] = 1.0;
+        tableau[i][width - 1] = 1.0;
+    }
+    for cell in tableau[row_count].iter_mut().take(column_count) {
+        *cell = -1.0;
+    }
+    let mut basis: Vec<usize> = (column_count..column_count + row_count).collect();
+
+    while let Some(entering) = (0..width - 1).find(|&j| tableau[row_count][j] < -EPSILON) {
+        let mut leaving: Option<usize> = None;
+        for i in 0..row_count {
+            if tableau[i][entering] > EPSILON {
+                let ratio = tableau[i][width - 1] / tableau[i][entering];
+                let is_better = match leaving {
+                    None => true,
+                    Some(l) => {
+                        let best = tableau[l][width - 1] / tableau[l][entering];
+                        ratio < best - EPSILON || (ratio < best + EPSILON && basis[i] < basis[l])
+                    }
+                };
+                if is_better {
+                    leaving = Some(i);
+                }
+            }
+        }
+        let leaving = leaving.expect("game LP is bounded");
+        pivot(&mut tableau, leaving, entering);
+        basis[leaving] = entering;
+    }
+
+    let total = tableau[row_count][width - 1];
+    let shifted_value = 1.0 / total;
+    let mut column_strategy = vec![0.0; column_count];
+    for (i, &b) in basis.iter().enumerate() {
+        if b < column_count {
+            column_strategy[b] = tableau[i][width - 1] * shifted_value;
+        }
+    }
+    let row_strategy = (0..row_count)
+        .map(|i| tableau[row_count][column_count + i] * shifted_value)
+        .collect();
+
+    MatrixSolution {
+        row_strategy: normalize(row_strategy),
+        column_strategy: normalize(column_strategy),
+        value: shifted_value - shift,
+    }
+}
+
+fn pivot(tableau: &mut [Vec<f64>], row: usize, column: usize) {
+    let divisor = tableau[row][column];
+    for cell in tableau[row].iter_mut() {
+        *cell /= divisor;
+    }
+    let pivot_row = tableau[row].clone();
+    for (i, other) in tableau.iter_mut().enumerate() {
+        if i != row {
+            let factor = other[column];
+            if factor.abs() > EPSILON {
+                for (cell, p) in other.iter_mut().zip(&pivot_row) {
+                    *cell -= factor * p;
+                }
+            }
+        }
+    }
+}
+
+fn normalize(strategy: Vec<f64>) -> Vec<f64> {
+    let clamped: Vec<f64> = strategy.into_iter().map(|p| p.max(0.0)).collect();
+    let total: f64 = clamped.iter().sum();
+    clamped.into_iter().map(|p| p / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Character;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-6,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn assert_equilibrium(payoffs: &[Vec<f64>], solution: &MatrixSolution) {
+        for j in 0..payoffs[0].len() {
+            let row_payoff: f64 = (0..payoffs.len())
+                .map(|i| solution.row_strategy[i] * payoffs[i][j])
+                .sum();
+            assert!(row_payoff >= solution.value - 1e-6);
+        }
+        for (i, row) in payoffs.iter().enumerate() {
+            let column_payoff: f64 = row
+                .iter()
+                .zip(&solution.column_strategy)
+                .map(|(p, q)| p * q)
+                .sum();
+            assert!(column_payoff <= solution.value + 1e-6, "row {}", i);
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_is_uniform_with_value_zero() {
+        let payoffs = vec![
+            vec![0.0, -1.0, 1.0],
+            vec![1.0, 0.0, -1.0],
+            vec![-1.0, 1.0, 0.0],
+        ];
+        let solution = solve_zero_sum(&payoffs);
+
+        assert_close(0.0, solution.value);
+        for p in solution
+            .row_strategy
+            .iter()
+            .chain(&solution.column_strategy)
+        {
+            assert_close(1.0 / 3.0, *p);
+        }
+    }
+
+    #[test]
+    fn dominant_strategy_is_pure() {
+        let payoffs = vec![vec![3.0, 2.0], vec![1.0, 0.0]];
+        let solution = solve_zero_sum(&payoffs);
+
+        assert_close(2.0, solution.value);
+        assert_close(1.0, solution.row_strategy[0]);
+        assert_close(1.0, solution.column_strategy[1]);
+    }
+
+    #[test]
+    fn asymmetric_game_is_in_equilibrium() {
+        let payoffs = vec![
+            vec![2.0, -1.0, 0.5],
+            vec![-1.0, 1.0, 0.0],
+            vec![0.0, 0.0, -2.0],
+            vec![1.0, -3.0, 4.0],
+        ];
+        let solution = solve_zero_sum(&payoffs);
+        assert_equilibrium(&payoffs, &solution);
+    }
+
+    #[test]
+    fn symmetric_character_headstart_game_has_value_zero() {
+        let characters = Character::all();
+        let equilibrium = solve_points_against(&characters, &characters);
+
+        assert_close(0.0, equilibrium.value);
+        let total: f64 = equilibrium.row_strategy.iter().map(|(_, p)| p).sum();
+        assert_close(1.0, total);
+    }
+}
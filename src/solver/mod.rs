@@ -0,0 +1,208 @@
+mod best_response;
+pub use best_response::{
+    best_response, exploitability, AgentPolicy, BestResponse, BestResponseConfig, Policy,
+};
+mod cfr;
+pub(crate) use cfr::sample;
+pub use cfr::{information_state_key, CfrConfig, CfrTrainer, CfrVariant, StrategyProfile};
+mod exact;
+pub use exact::{solve_exact, ExactConfig, ExactSolution};
+mod matrix;
+pub use matrix::{solve_points_against, solve_zero_sum, Equilibrium, MatrixSolution};
+mod transposition;
+pub use transposition::TranspositionTable;
+
+use crate::agents::rewards;
+use crate::analysis::resolve_round;
+use crate::choices::Action;
+use crate::game::BatchChoiceGame;
+
+const DESTROYED_ITEM_COST: f64 = 0.5;
+
+pub fn solve_action_phase(game: &BatchChoiceGame) -> Option<Equilibrium<Action>> {
+    if game.player_count() != 2 {
+        return None;
+    }
+
+    let rows = game.choices_for(0)?.actions()?;
+    let columns = game.choices_for(1)?.actions()?;
+    let payoffs: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|&row| {
+            columns
+                .iter()
+                .map(|&column| round_payoff(game, row, column))
+                .collect()
+        })
+        .collect();
+    let solution = solve_zero_sum(&payoffs);
+
+    Some(Equilibrium {
+        row_strategy: rows.into_iter().zip(solution.row_strategy).collect(),
+        column_strategy: columns.into_iter().zip(solution.column_strategy).collect(),
+        value: solution.value,
+    })
+}
+
+fn round_payoff(game: &BatchChoiceGame, row: Action, column: Action) -> f64 {
+    let result = resolve_round(game, &[row, column]);
+    let points_to_win = f64::from(game.config().points_to_win);
+    match result.winner {
+        Some(0) => points_to_win,
+        Some(_) => -points_to_win,
+        None => {
+            let destroyed = |i: usize| if result.destroyed[i] { 1.0 } else { 0.0 };
+            f64::from(result.points_gained[0] - result.points_gained[1])
+                - DESTROYED_ITEM_COST * (destroyed(0) - destroyed(1))
+        }
+    }
+}
+
+pub(crate) fn utility(game: &BatchChoiceGame, player_index: usize) -> f64 {
+    let rewards = rewards(game);
+    rewards[player_index] - rewards[1 - player_index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{
+        ArsenalItem, BatchChoice, Booster, Character, DequeueChoice, Move, MoveOutcomes,
+        PointsAgainst,
+    };
+    use crate::game::Config;
+    use crate::outcomes::Outcome;
+    use crate::random::Prng;
+
+    #[test]
+    fn solve_action_phase_returns_none_outside_action_phase() {
+        assert!(solve_action_phase(&BatchChoiceGame::default()).is_none());
+    }
+
+    #[test]
+    fn solve_action_phase_returns_none_for_more_than_two_players() {
+        let game = BatchChoiceGame::new(Config {
+            player_count: 3,
+            ..Config::default()
+        });
+        assert!(solve_action_phase(&game).is_none());
+    }
+
+    #[test]
+    fn solve_action_phase_covers_each_players_actions() {
+        let mut game = BatchChoiceGame::default();
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+            DequeueChoice::DrainAndExit(ArsenalItem::Mirror),
+        ]))
+        .unwrap();
+
+        let equilibrium = solve_action_phase(&game).unwrap();
+        let ninja_actions: Vec<Action> = equilibrium.row_strategy.iter().map(|(a, _)| *a).collect();
+        assert_eq!(
+            game.choices_for(0).unwrap().actions().unwrap(),
+            ninja_actions
+        );
+        let total: f64 = equilibrium.column_strategy.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn solve_action_phase_follows_config_move_outcomes() {
+        let mut move_outcomes = MoveOutcomes::default();
+        for m in Move::all() {
+            if m != Move::Kick {
+                move_outcomes.set(Move::Kick, m, 1);
+                move_outcomes.set(m, Move::Kick, 0);
+            }
+        }
+        let mut game = BatchChoiceGame::new(Config {
+            move_outcomes,
+            ..Config::default()
+        });
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Samurai,
+        ]))
+        .unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, Booster::Atlas]))
+            .unwrap();
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::Decline,
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+
+        let equilibrium = solve_action_phase(&game).unwrap();
+        let kick = equilibrium
+            .row_strategy
+            .iter()
+            .find(|(a, _)| *a == Action::Move(Move::Kick))
+            .unwrap();
+        assert!((kick.1 - 1.0).abs() < 1e-6);
+        assert!(equilibrium.value > 0.0);
+    }
+
+    #[test]
+    fn round_payoffs_follow_engine_scoring() {
+        let config = Config {
+            points_to_win: 2,
+            max_state_repetitions: Some(3),
+            max_rounds_without_progress: Some(20),
+            ..Config::default()
+        };
+        let mut deducted = false;
+        let mut destroyed = false;
+        for seed in 0..20 {
+            let mut rng = Prng::new(seed);
+            let mut game = BatchChoiceGame::new(config.clone());
+            while let Some(choice) = game.choices().random(&mut rng) {
+                if let Some(rows) = game.choices_for(0).and_then(|c| c.actions()) {
+                    let columns = game.choices_for(1).unwrap().actions().unwrap();
+                    for &row in &rows {
+                        for &column in &columns {
+                            let mut next = game.clone();
+                            let apds = match next.choose(BatchChoice::Actions(vec![row, column])) {
+                                Ok(Outcome::ActionPhaseDone(apds))
+                                | Ok(Outcome::GameOver(apds))
+                                | Ok(Outcome::Draw(apds)) => apds,
+                                other => panic!("unexpected outcome {:?}", other),
+                            };
+                            let gained = |i: usize| {
+                                f64::from(next.points(i).unwrap())
+                                    - f64::from(game.points(i).unwrap())
+                            };
+                            let cost = |i: usize| {
+                                if apds[i].2 && apds[i].0 != Action::Concede {
+                                    DESTROYED_ITEM_COST
+                                } else {
+                                    0.0
+                                }
+                            };
+                            let expected = match next.winner_index() {
+                                Some(0) => 2.0,
+                                Some(_) => -2.0,
+                                None => gained(0) - gained(1) - cost(0) + cost(1),
+                            };
+
+                            assert_eq!(expected, round_payoff(&game, row, column));
+                            let raw = Action::points_of(&[row, column]);
+                            deducted |= (0..2).any(|i| apds[i].1 != raw[i] as i8);
+                            destroyed |= cost(0) != cost(1);
+                        }
+                    }
+                }
+                game.choose(choice).unwrap();
+            }
+        }
+        assert!(deducted);
+        assert!(destroyed);
+    }
+}
@@ -0,0 +1,131 @@
+#[derive(Debug, Clone)]
+pub struct TranspositionTable<V> {
+    slots: Vec<Option<(u64, V)>>,
+    len: usize,
+}
+
+impl<V> TranspositionTable<V> {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1).next_power_of_two();
+        Self {
+            slots: (0..capacity).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, key: u64) -> Option<&V> {
+        match &self.slots[self.slot(key)] {
+            Some((k, value)) if *k == key => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: u64) -> Option<&mut V> {
+        let slot = self.slot(key);
+        match &mut self.slots[slot] {
+            Some((k, value)) if *k == key => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn contains_key(&self, key: u64) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn insert(&mut self, key: u64, value: V) -> Option<(u64, V)> {
+        let slot = self.slot(key);
+        let replaced = self.slots[slot].replace((key, value));
+        if replaced.is_none() {
+            self.len += 1;
+        }
+        replaced
+    }
+
+    pub fn remove(&mut self, key: u64) -> Option<V> {
+        let slot = self.slot(key);
+        match &self.slots[slot] {
+            Some((k, _)) if *k == key => {
+                self.len -= 1;
+                self.slots[slot].take().map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for slot in &mut self.slots {
+            *slot = None;
+        }
+        self.len = 0;
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        (key as usize) & (self.slots.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::{BatchChoice, Character};
+    use crate::game::BatchChoiceGame;
+
+    #[test]
+    fn capacity_rounds_up_to_power_of_two() {
+        assert_eq!(1, TranspositionTable::<()>::new(0).capacity());
+        assert_eq!(8, TranspositionTable::<()>::new(5).capacity());
+    }
+
+    #[test]
+    fn stores_and_retrieves_values() {
+        let mut table = TranspositionTable::new(16);
+        assert!(table.insert(3, "three").is_none());
+        assert_eq!(Some(&"three"), table.get(3));
+        assert_eq!(None, table.get(4));
+        *table.get_mut(3).unwrap() = "drei";
+        assert_eq!(Some("drei"), table.remove(3));
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn colliding_keys_replace_older_entries() {
+        let mut table = TranspositionTable::new(4);
+        table.insert(1, 'a');
+        assert_eq!(Some((1, 'a')), table.insert(5, 'b'));
+        assert_eq!(None, table.get(1));
+        assert_eq!(Some(&'b'), table.get(5));
+        assert_eq!(1, table.len());
+    }
+
+    #[test]
+    fn dedupes_game_states_by_zobrist_key() {
+        let mut table = TranspositionTable::new(64);
+        let mut game = BatchChoiceGame::default();
+        table.insert(game.zobrist_key(), 0);
+
+        game.choose(BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Zombie,
+        ]))
+        .unwrap();
+        assert!(!table.contains_key(game.zobrist_key()));
+        table.insert(game.zobrist_key(), 1);
+        assert_eq!(Some(&1), table.get(game.clone().zobrist_key()));
+
+        table.clear();
+        assert!(table.is_empty());
+        assert_eq!(None, table.get(game.zobrist_key()));
+    }
+}